@@ -1,33 +1,1247 @@
+// the scheduler functions all take a borrowed Vec<Process> (see the borrowing notes in scheduler.rs),
+// clippy would prefer slices, this tells it that borrowing the Vec is intentional
+#![allow(clippy::ptr_arg)]
+
 // think of as import or include
 mod scheduler;
 
 // similar to namespaces
 use std::{env};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
 use scheduler::*;
 
+// constants declaration, alpha is how much SPN's prediction trusts the last burst over the older history
+const SPNALPHA: f32 = 0.5;
+// power used per unit of time by the CPU while running a process and while idle, for --energy
+const ACTIVEPOWER: f32 = 1.0;
+const IDLEPOWER: f32 = 0.1;
+
+fn usage() {
+    println!("usage: cargo run -- [fifo|sjf|stcf|rr|mlfq|spn|priority|gang|two-level|cfs|edf|hybrid|ljf|aging|memory|smith|rr-classed|rr-multi] workload_file [--dispatch-order] [--inversion] [--demotion full-slice|allotment=N] [--boost to-top|one-level] [--speed X] [--mlfq-format compact|verbose] [--repeat N] [--running-average] [--fingerprint] [--cpus N] [--quantum Q] [--quiet] [--shuffle-ties SEED] [--energy] [--max-in-memory N] [--gantt] [--gantt-scale N] [--gantt-json] [--arrivals absolute|delta] [--sqlite results.db] [--vs-sjf] [--vs-ps] [--interactive] [--queue-stats] [--min-granularity N] [--dispatch-overhead X] [--lenient] [--detect-starvation THRESHOLD] [--deadlines] [--relative] [--stream] [--duration-histogram] [--at t,t,...] [--periodic] [--events events.txt] [--spans] [--check-conservation] [--tick GRANULARITY] [--format full|line] [--queueing-delay] [--window N] [--bucket-by-arrival N] [--gantt-vs ALGORITHM] [--preemptions] [--live PIPE] [--critical-path] [--sensitivity PID:DELTA] [--peak-footprint] [--aging-rate X] [--core-trace] [--abort-on-miss stop|skip] [--checkpoint state.txt] [--checkpoint-every N] [--stop-at T] [--resume state.txt] [--load] [--preempt-on-arrival] [--total-memory X] [--completion-cv] [--partial-ticks] [--vcd schedule.vcd] [--csv results.csv] [--annotate-csv] [--weighted-completion] [--classes N] [--stream-output] [--window-metrics WIDTH] [--replay-gantt chart.json] [--top-n K] [--response-variance] [--affinity-wait N]");
+    println!("       cargo run -- all workload_file [--only algorithm,algorithm,...]");
+    println!("       cargo run -- workload_file --compare-fairness [--only algorithm,algorithm,...]");
+    println!("       cargo run -- workload_file --compare-optimal [--only algorithm,algorithm,...]");
+    println!("       cargo run -- workload_file --sweep-quantum START:END:STEP");
+    println!("       cargo run -- convert input_workload output_workload [--arrivals absolute|delta]");
+    println!("       cargo run -- selftest");
+    println!("       cargo run -- gen N [--model uniform|poisson|md1] [--seed S] [--max-gap N] [--max-duration N] [--jitter N] [--lambda X] [--mean-service X]");
+}
+
+// reads the value that comes after an option, printing an error and returning None if it is missing or doesn't parse
+// T is generic so this works for any type that can be parsed from a string (the FromStr trait), like f32 or usize
+fn option_value<T: std::str::FromStr>(option: &str, value: Option<&String>) -> Option<T> {
+    let parsed = value.and_then(|v| v.parse().ok());
+    if parsed.is_none() {
+        println!("Error: {option} needs a value");
+        usage();
+    }
+    parsed
+}
+
+// every algorithm run_algorithm knows about
+const ALGORITHMS: [&str; 18] = ["fifo", "sjf", "stcf", "rr", "mlfq", "spn", "priority", "gang", "two-level", "cfs", "edf", "hybrid", "ljf", "aging", "memory", "smith", "rr-classed", "rr-multi"];
+
+// runs the algorithm with the given name, None if there is no algorithm with that name
+// input: algorithm name, borrowed Vector of Processes, borrowed SimConfig, output: Option of ScheduleResult
+fn run_algorithm(algo: &str, wkld: &Vec<Process>, config: &SimConfig) -> Option<ScheduleResult> {
+    let result = match algo { // switch statement equivalent
+        "fifo" => fifo(wkld),
+        "sjf" => sjf(wkld),
+        "stcf" => stcf(wkld, config),
+        "rr" => rr(wkld, config),
+        "mlfq" => mlfq(wkld, config),
+        "spn" => spn_predicted(wkld, SPNALPHA),
+        "priority" => priority(wkld),
+        "gang" => gang(wkld, config.cpus),
+        "two-level" => two_level(wkld, config),
+        "cfs" => cfs(wkld, config),
+        "edf" => edf(wkld, config),
+        "hybrid" => hybrid(wkld, config),
+        "ljf" => ljf(wkld),
+        "aging" => aging_priority(wkld, config),
+        "memory" => memory_constrained(wkld, config.total_memory),
+        "smith" => smiths_rule(wkld),
+        "rr-classed" => rr_classed(wkld, config),
+        "rr-multi" => rr_multi(wkld, config),
+        _ => return None,
+    };
+    // running a process before it arrived is a bug in the algorithm, debug builds (cargo run, cargo test) stop right
+    // there instead of printing metrics that look fine. Release builds skip the check
+    #[cfg(debug_assertions)]
+    if let Err(problem) = check_no_early_start(&result.processes) {
+        panic!("{algo} made an impossible schedule: {problem}");
+    }
+    Some(result)
+}
+
+// runs every algorithm on a handful of generated workloads and checks each schedule with validate_schedule and check_conservation,
+// printing PASS or FAIL per algorithm, and checks that check_no_early_start catches a bad schedule, custom_schedule
+// against fifo, what changes the fingerprint, that a finished schedule reschedules from scratch and that a NaN duration
+// sorts last. It is a quick check that works on a release build without cargo test
+// input: None, output: true if every algorithm passed
+fn selftest() -> bool {
+    let config = SimConfig { mlfq_printing: false, ..SimConfig::default() };
+    // a mix of uniform and Poisson workloads, different seeds give different arrival patterns including idle gaps
+    let mut workloads = Vec::new();
+    for seed in 1..=5 {
+        workloads.push(generate_workload(20, 5, 20, 0, seed));
+        workloads.push(generate_poisson_workload(20, 5.0, 4.0, seed));
+    }
+
+    let mut all_passed = true;
+    for algo in ALGORITHMS {
+        let mut failure = None;
+        for (i, wkld) in workloads.iter().enumerate() {
+            let result = run_algorithm(algo, wkld, &config).unwrap();
+            if let Err(problem) = validate_schedule(wkld, &result).and_then(|_| check_conservation(&result, 1.0, 1.0)) {
+                failure = Some(format!("workload {i}: {problem}"));
+                break;
+            }
+        }
+        match failure {
+            None => println!("{algo}: PASS"),
+            Some(problem) => {
+                println!("{algo}: FAIL ({problem})");
+                all_passed = false;
+            }
+        }
+    }
+
+    // a schedule with a process moved to before its arrival, check_no_early_start has to catch it
+    let mut bad = fifo(&workloads[0]);
+    bad.processes[1].first_run = bad.processes[1].arrival - 1.0;
+    match check_no_early_start(&bad.processes) {
+        Err(problem) => println!("check_no_early_start (a process run before it arrives): PASS ({problem})"),
+        Ok(()) => {
+            println!("check_no_early_start (a process run before it arrives): FAIL (it wasn't flagged)");
+            all_passed = false;
+        }
+    }
+
+    // FIFO written as a custom_schedule closure, always the earliest arrival (the ready processes are in arrival order,
+    // ties in pid order), has to make exactly the schedule fifo does
+    let same_as_fifo = workloads.iter().all(|wkld| {
+        let custom = custom_schedule(wkld, |_, _| 0);
+        schedule_fingerprint(&custom.processes) == schedule_fingerprint(&fifo(wkld).processes)
+    });
+    if same_as_fifo {
+        println!("custom_schedule (fifo as a closure): PASS");
+    } else {
+        println!("custom_schedule (fifo as a closure): FAIL (the schedule isn't the same as fifo's)");
+        all_passed = false;
+    }
+
+    // the fingerprint hashes the processes in pid order, so the same schedule listed backwards has the same one, and
+    // moving a single completion by one tick has to give a different one
+    let schedule = fifo(&workloads[0]).processes;
+    let mut reversed = schedule.clone();
+    reversed.reverse();
+    let mut moved = schedule.clone();
+    moved[3].completion += 1.0;
+    let fingerprint = schedule_fingerprint(&schedule);
+    if schedule_fingerprint(&reversed) == fingerprint && schedule_fingerprint(&moved) != fingerprint {
+        println!("schedule_fingerprint (reordered the same, one completion changed different): PASS");
+    } else {
+        println!("schedule_fingerprint (reordered the same, one completion changed different): FAIL");
+        all_passed = false;
+    }
+
+    // a finished schedule fed back into fifo still has every first_run, completion and remaining_time set, reset has to
+    // clear them so fifo makes the same schedule again from scratch instead of building on the stale ones
+    let reschedules = workloads.iter().all(|wkld| {
+        let finished = fifo(wkld).processes;
+        schedule_fingerprint(&fifo(&finished).processes) == schedule_fingerprint(&finished)
+    });
+    if reschedules {
+        println!("Process::reset (a finished schedule run through fifo again): PASS");
+    } else {
+        println!("Process::reset (a finished schedule run through fifo again): FAIL (the schedule changed)");
+        all_passed = false;
+    }
+
+    // a NaN duration used to panic in the comparisons, OrderedF32 sorts it after every number instead
+    let mut with_nan = vec![Process::new(0.0, 3.0, 0.0, 0.0), Process::new(0.0, f32::NAN, 0.0, 0.0), Process::new(0.0, 1.0, 0.0, 0.0)];
+    for (pid, p) in with_nan.iter_mut().enumerate() {
+        p.pid = pid;
+    }
+    let order: Vec<usize> = optimal_offline(&with_nan).processes.iter().map(|p| p.pid).collect();
+    if order == vec![2, 0, 1] {
+        println!("OrderedF32 (a NaN duration sorts last): PASS");
+    } else {
+        println!("OrderedF32 (a NaN duration sorts last): FAIL (the order was {order:?})");
+        all_passed = false;
+    }
+    all_passed
+}
+
+// runs each of the algorithms on the workload and prints a table of their main metrics, one row per algorithm in the
+// order given, to compare them side by side
+// input: borrowed Vector of Processes, borrowed SimConfig, borrowed Vector of algorithm names, output: None
+fn compare_all(wkld: &Vec<Process>, config: &SimConfig, algorithms: &Vec<&str>) {
+    println!("{:<10} {:>12} {:>12} {:>10}", "algorithm", "turnaround", "response", "makespan");
+    for &algo in algorithms {
+        let result = run_algorithm(algo, wkld, config).unwrap();
+        println!("{:<10} {:>12.2} {:>12.2} {:>10}", algo, avg_turnaround(&result.processes),
+            avg_response(&result.processes), makespan(&result.processes));
+    }
+}
+
+// runs every algorithm on the workload and prints a table of how fair each one was to the processes, the Jain fairness
+// index of the turnaround times and the longest turnaround over the shortest, fairest first
+// input: borrowed Vector of Processes, borrowed SimConfig, output: None
+fn compare_fairness(wkld: &Vec<Process>, config: &SimConfig, algorithms: &Vec<&str>) {
+    let mut rows: Vec<(&str, f32, Option<f32>)> = algorithms.iter().map(|&algo| {
+        let result = run_algorithm(algo, wkld, config).unwrap();
+        (algo, jain_fairness(&result.processes), turnaround_ratio(&result.processes))
+    }).collect();
+    rows.sort_by_key(|row| std::cmp::Reverse(OrderedF32(row.1)));
+    // {:<10} pads to 10 characters on the left, {:>8.4} pads to 8 on the right with 4 decimal places
+    println!("{:<10} {:>8} {:>18}", "algorithm", "jain", "max/min turnaround");
+    for (algo, jain, ratio) in rows {
+        // n/a when the shortest turnaround is 0, there is nothing to divide by
+        let ratio = ratio.map_or("n/a".to_string(), |r| format!("{r:.2}"));
+        println!("{:<10} {:>8.4} {:>18}", algo, jain, ratio);
+    }
+}
+
+// runs each of the algorithms on the workload and prints how far each one's mean completion time (from the earliest
+// arrival) is from optimal_offline's, the best any order could do if everything were there from the start
+// input: borrowed Vector of Processes, borrowed SimConfig, borrowed Vector of algorithm names, output: None
+fn compare_optimal(wkld: &Vec<Process>, config: &SimConfig, algorithms: &Vec<&str>) {
+    let start = wkld.iter().fold(f32::MAX, |acc, p| acc.min(p.arrival));
+    let mean_completion = |processes: &Vec<Process>| processes.iter().map(|p| p.completion - start).sum::<f32>() / processes.len() as f32;
+    let optimal = mean_completion(&optimal_offline(wkld).processes);
+    println!("Optimal Mean Completion: {optimal} (every process available at t={start}, shortest first)");
+    println!("{:<10} {:>16} {:>12}", "algorithm", "mean completion", "vs optimal");
+    for &algo in algorithms {
+        let completion = mean_completion(&run_algorithm(algo, wkld, config).unwrap().processes);
+        let difference = (completion - optimal) / optimal * 100.0;
+        println!("{:<10} {:>16.2} {:>+11.1}%", algo, completion, difference);
+    }
+}
+
+// runs rr on the workload once for every quantum from start to end (including end) in steps of step, and prints a
+// table of the average turnaround and the number of context switches each quantum gave, to see the tradeoff between
+// a short quantum (responsive, lots of switching) and a long one (less switching, closer to FIFO)
+// input: borrowed Vector of Processes, borrowed SimConfig, start, end, step, output: None
+fn sweep_quantum(wkld: &Vec<Process>, config: &SimConfig, start: f32, end: f32, step: f32) {
+    // counting steps instead of adding step up each time, so 0.1 steps don't drift and miss the end
+    let steps = ((end - start) / step + 0.0001).floor() as usize;
+    println!("{:>8} {:>12} {:>16}", "quantum", "turnaround", "context_switches");
+    for i in 0..=steps {
+        let quantum = start + i as f32 * step;
+        let result = rr(wkld, &SimConfig { quantum, ..config.clone() });
+        println!("{:>8} {:>12.2} {:>16}", quantum, avg_turnaround(&result.processes), context_switches(&result.segments));
+    }
+}
+
+// saves the results to the SQLite database at db_path with scheduler::write_sqlite, which only exists when the
+// program is built with the sqlite feature
+#[cfg(feature = "sqlite")]
+fn write_results_db(db_path: &str, algo: &str, wkld_path: &str, processes: &Vec<Process>) {
+    if let Err(message) = write_sqlite(db_path, algo, wkld_path, processes) {
+        println!("Error: could not write to {db_path}: {message}");
+    }
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn write_results_db(_db_path: &str, _algo: &str, _wkld_path: &str, _processes: &Vec<Process>) {
+    println!("Error: --sqlite needs the sqlite feature, run with \"cargo run --features sqlite -- ...\"");
+}
+
+// runs fifo_streaming on the workload file and prints each process as it completes, then the same metrics as
+// show_metrics. The averages are running sums, added up in the same order avg_turnaround and avg_response add them so
+// they come out exactly the same, nothing is kept per process
+// input: workload path, arrival format, relative, output: None
+fn run_streaming_fifo(wkld_path: &str, arrivals: ArrivalFormat, relative: bool) {
+    let file = match File::open(wkld_path) {
+        Ok(file) => file,
+        Err(e) => {
+            println!("Error: {}", WorkloadError::Io(e.to_string()));
+            return;
+        }
+    };
+    let mut n = 0;
+    let mut turnaround_sum = 0.0;
+    let mut response_sum = 0.0;
+    let mut first_arrival = f32::MAX;
+    let mut last_completion = f32::MIN;
+    if relative {
+        println!("Processes (first_run and completion relative to arrival):");
+    } else {
+        println!("Processes:");
+    }
+    let streamed = fifo_streaming(BufReader::new(file), arrivals, |p| {
+        show_process(&p, relative);
+        n += 1;
+        turnaround_sum = turnaround_sum + p.completion - p.arrival;
+        response_sum = response_sum + p.first_run - p.arrival;
+        first_arrival = first_arrival.min(p.arrival);
+        last_completion = last_completion.max(p.completion);
+    });
+    if let Err(e) = streamed {
+        println!("Error: {e}");
+        return;
+    }
+    println!("Average Turnaround Time: {}", turnaround_sum / n as f32);
+    println!("Average Response Time:   {}", response_sum / n as f32);
+    println!("Makespan:                {}", last_completion - first_arrival);
+}
+
+// installs a ctrl-c (SIGINT) handler that sets scheduler::INTERRUPTED, so a long simulation can be stopped
+// and still print metrics for everything that finished. signal() comes from the C standard library, which Rust
+// links anyway, so it is declared here directly instead of pulling in a crate for it. Calling into C is unsafe because
+// the compiler can't check it. The handler also puts SIGINT back to its default, so pressing ctrl-c twice quits for real
+#[cfg(unix)]
+fn install_interrupt_handler() {
+    const SIGINT: i32 = 2;
+    const SIG_DFL: usize = 0;
+    extern "C" {
+        fn signal(signum: i32, handler: usize) -> usize;
+    }
+    extern "C" fn on_interrupt(_signum: i32) {
+        INTERRUPTED.store(true, Ordering::SeqCst);
+        unsafe { signal(SIGINT, SIG_DFL) };
+    }
+    unsafe { signal(SIGINT, on_interrupt as extern "C" fn(i32) as usize) };
+}
+
+// ctrl-c just ends the program like normal on everything else
+#[cfg(not(unix))]
+fn install_interrupt_handler() {}
+
 fn main(){
     let args: Vec<String> = env::args().collect(); // reads command line arguments into a vector, similar to C++ vector STL
-    if args.len() != 3 {
-        println!("usage: cargo run -- [fifo|sjf|stcf|rr|mlfq] workload_file");
+
+    // anything starting with -- is an option, everything else is the algorithm and the workload file in that order
+    let mut positional: Vec<&String> = Vec::new();
+    let mut dispatch_order = false;
+    let mut inversion = false;
+    let mut fingerprint = false;
+    let mut quiet = false;
+    let mut line_format = false;
+    let mut energy = false;
+    let mut gantt = false;
+    let mut gantt_json = false;
+    let mut gantt_scale: f32 = 1.0;
+    let mut fairness = false;
+    let mut vs_optimal = false;
+    let mut vs_sjf = false;
+    let mut vs_ps = false;
+    let mut interactive = false;
+    let mut queue_stats = false;
+    let mut lenient = false;
+    let mut deadlines = false;
+    let mut relative = false;
+    let mut stream = false;
+    let mut histogram = false;
+    let mut periodic = false;
+    let mut spans = false;
+    let mut preemptions = false;
+    let mut conservation = false;
+    let mut queueing_delay = false;
+    let mut critical = false;
+    let mut load = false;
+    let mut completion_cv = false;
+    let mut response_var = false;
+    let mut quantum_given = false;
+    let mut weighted = false;
+    let mut footprint = false;
+    let mut core_trace = false;
+    let mut arrivals = ArrivalFormat::default();
+    // which algorithms all and --compare-fairness run, every one unless --only is given
+    let mut only: Vec<&str> = ALGORITHMS.to_vec();
+    let mut repeat: Option<usize> = None;
+    let mut vcd_path: Option<String> = None;
+    let mut replay_path: Option<String> = None;
+    let mut csv_path: Option<String> = None;
+    let mut annotate_csv = false;
+    let mut sweep: Option<(f32, f32, f32)> = None;
+    let mut shuffle_seed: Option<u64> = None;
+    let mut sqlite_path: Option<String> = None;
+    let mut events_path: Option<String> = None;
+    let mut live_path: Option<String> = None;
+    let mut checkpoint_path: Option<String> = None;
+    let mut checkpoint_every: Option<f32> = None;
+    let mut stop_at: Option<f32> = None;
+    let mut resume_path: Option<String> = None;
+    // the algorithm whose Gantt chart goes under this one's with --gantt-vs
+    let mut gantt_vs: Option<String> = None;
+    let mut starvation_threshold: Option<f32> = None;
+    // the pid whose duration --sensitivity changes, and by how much
+    let mut sensitivity: Option<(usize, f32)> = None;
+    let mut bucket_width: Option<f32> = None;
+    let mut window_width: Option<f32> = None;
+    let mut top_n: Option<usize> = None;
+    let mut at_times: Vec<f32> = Vec::new();
+    // settings only used by the gen command
+    let mut model = String::from("uniform");
+    let mut seed: u64 = 377;
+    let mut max_gap: i32 = 5;
+    let mut max_duration: i32 = 20;
+    let mut jitter: i32 = 0;
+    let mut lambda: f64 = 5.0;
+    let mut mean_service: f64 = 4.0;
+    let mut config = SimConfig::default();
+    let mut arg_iter = args.iter().skip(1);
+    while let Some(arg) = arg_iter.next() {
+        match arg.as_str() {
+            "--dispatch-order" => dispatch_order = true,
+            "--inversion" => inversion = true,
+            "--fingerprint" => fingerprint = true,
+            "--running-average" => config.running_average = true,
+            "--quiet" => quiet = true,
+            "--energy" => energy = true,
+            "--gantt" => gantt = true,
+            "--gantt-json" => gantt_json = true,
+            "--compare-fairness" => fairness = true,
+            "--compare-optimal" => vs_optimal = true,
+            "--vs-sjf" => vs_sjf = true,
+            "--vs-ps" => vs_ps = true,
+            "--interactive" => interactive = true,
+            "--lenient" => lenient = true,
+            "--deadlines" => deadlines = true,
+            "--relative" => relative = true,
+            "--stream" => stream = true,
+            "--duration-histogram" => histogram = true,
+            "--periodic" => periodic = true,
+            "--spans" => spans = true,
+            "--preemptions" => preemptions = true,
+            "--check-conservation" => conservation = true,
+            "--queueing-delay" => queueing_delay = true,
+            "--critical-path" => critical = true,
+            "--load" => load = true,
+            "--completion-cv" => completion_cv = true,
+            "--response-variance" => response_var = true,
+            "--weighted-completion" => weighted = true,
+            "--peak-footprint" => footprint = true,
+            "--core-trace" => core_trace = true,
+            "--queue-stats" => queue_stats = true,
+            // options that take a value read the next argument
+            "--demotion" => {
+                let value = arg_iter.next().map(|v| v.as_str()).unwrap_or("");
+                let allotment = value.strip_prefix("allotment=").and_then(|n| n.parse().ok());
+                config.demotion = match (value, allotment) {
+                    ("full-slice", _) => DemotionPolicy::FullSlice,
+                    (_, Some(n)) => DemotionPolicy::Allotment(n),
+                    _ => {
+                        println!("Error: Unknown demotion policy: {value}");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--at" => {
+                at_times = match arg_iter.next().map(|v| v.split(',').map(|t| t.parse()).collect::<Result<Vec<f32>, _>>()) {
+                    Some(Ok(times)) => times,
+                    _ => {
+                        println!("Error: --at needs a comma separated list of times");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--sensitivity" => {
+                let parsed = arg_iter.next().and_then(|v| v.split_once(':').map(|(pid, delta)| (pid.parse(), delta.parse())));
+                sensitivity = match parsed {
+                    Some((Ok(pid), Ok(delta))) if delta != 0.0 => Some((pid, delta)),
+                    _ => {
+                        println!("Error: --sensitivity needs a pid and a change to its duration that isn't 0, like 3:5");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--sweep-quantum" => {
+                let parsed: Vec<f32> = arg_iter.next().map(|v| v.split(':').filter_map(|n| n.parse().ok()).collect()).unwrap_or_default();
+                sweep = match parsed[..] {
+                    [start, end, step] if start > 0.0 && end >= start && step > 0.0 => Some((start, end, step)),
+                    _ => {
+                        println!("Error: --sweep-quantum needs a start quantum greater than 0, an end at least as big and a step greater than 0, like 1:10:1");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--only" => {
+                let names: Vec<&str> = arg_iter.next().map(|v| v.split(',').collect()).unwrap_or_default();
+                if let Some(unknown) = names.iter().find(|name| !ALGORITHMS.contains(name)) {
+                    println!("Error: Unknown algorithm in --only: {unknown}");
+                    usage();
+                    return;
+                }
+                if names.is_empty() {
+                    println!("Error: --only needs a comma separated list of algorithms");
+                    usage();
+                    return;
+                }
+                only = names;
+            }
+            "--arrivals" => {
+                arrivals = match arg_iter.next().map(|v| v.as_str()) {
+                    Some("absolute") => ArrivalFormat::Absolute,
+                    Some("delta") => ArrivalFormat::Delta,
+                    _ => {
+                        println!("Error: --arrivals needs absolute or delta");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--boost" => {
+                config.boost = match arg_iter.next().map(|v| v.as_str()) {
+                    Some("to-top") => BoostPolicy::ToTop,
+                    Some("one-level") => BoostPolicy::OneLevel,
+                    _ => {
+                        println!("Error: --boost needs to-top or one-level");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--abort-on-miss" => {
+                config.on_miss = match arg_iter.next().map(|v| v.as_str()) {
+                    Some("stop") => Some(MissAction::Stop),
+                    Some("skip") => Some(MissAction::Skip),
+                    _ => {
+                        println!("Error: --abort-on-miss needs stop or skip");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--format" => {
+                line_format = match arg_iter.next().map(|v| v.as_str()) {
+                    Some("full") => false,
+                    Some("line") => true,
+                    _ => {
+                        println!("Error: --format needs full or line");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--mlfq-format" => {
+                config.mlfq_format = match arg_iter.next().map(|v| v.as_str()) {
+                    Some("compact") => MlfqFormat::Compact,
+                    Some("verbose") => MlfqFormat::Verbose,
+                    _ => {
+                        println!("Error: --mlfq-format needs compact or verbose");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--repeat" => {
+                repeat = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(n) if n > 0 => Some(n),
+                    _ => {
+                        println!("Error: --repeat needs a whole number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--cpus" => {
+                config.cpus = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(n) if n > 0 => n,
+                    _ => {
+                        println!("Error: --cpus needs a whole number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--max-in-memory" => {
+                config.max_in_memory = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(n) if n > 0 => n,
+                    _ => {
+                        println!("Error: --max-in-memory needs a whole number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--min-granularity" => {
+                config.min_granularity = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(granularity) if granularity > 0.0 => granularity,
+                    _ => {
+                        println!("Error: --min-granularity needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--dispatch-overhead" => {
+                config.dispatch_overhead = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(overhead) if overhead >= 0.0 => overhead,
+                    _ => {
+                        println!("Error: --dispatch-overhead needs a number that is 0 or more");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--detect-starvation" => {
+                starvation_threshold = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(threshold) if threshold >= 0.0 => Some(threshold),
+                    _ => {
+                        println!("Error: --detect-starvation needs a number that is 0 or more");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--bucket-by-arrival" => {
+                bucket_width = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(width) if width > 0.0 => Some(width),
+                    _ => {
+                        println!("Error: --bucket-by-arrival needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--top-n" => {
+                top_n = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(k) if k > 0 => Some(k),
+                    _ => {
+                        println!("Error: --top-n needs a whole number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--affinity-wait" => {
+                config.affinity_wait = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(wait) if wait >= 0.0 => wait,
+                    _ => {
+                        println!("Error: --affinity-wait needs a number that is 0 or more");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--window-metrics" => {
+                window_width = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(width) if width > 0.0 => Some(width),
+                    _ => {
+                        println!("Error: --window-metrics needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--gantt-scale" => {
+                gantt_scale = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(scale) if scale > 0.0 => scale,
+                    _ => {
+                        println!("Error: --gantt-scale needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--quantum" => {
+                quantum_given = true;
+                config.quantum = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(quantum) if quantum > 0.0 => quantum,
+                    _ => {
+                        println!("Error: --quantum needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--classes" => {
+                config.classes = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(classes) if classes > 0 => classes,
+                    _ => {
+                        println!("Error: --classes needs a whole number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--window" => {
+                config.window = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(window) if window > 0.0 => window,
+                    _ => {
+                        println!("Error: --window needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--total-memory" => {
+                config.total_memory = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(memory) if memory > 0.0 => memory,
+                    _ => {
+                        println!("Error: --total-memory needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--aging-rate" => {
+                config.aging_rate = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(rate) if rate >= 0.0 => rate,
+                    _ => {
+                        println!("Error: --aging-rate needs a number that isn't negative");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--preempt-on-arrival" => config.preempt_on_arrival = true,
+            "--partial-ticks" => config.partial_ticks = true,
+            "--stream-output" => config.stream_output = true,
+            "--tick" => {
+                config.tick = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(tick) if tick > 0.0 => tick,
+                    _ => {
+                        println!("Error: --tick needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--speed" => {
+                config.speed = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(speed) if speed > 0.0 => speed,
+                    _ => {
+                        println!("Error: --speed needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--sqlite" => match option_value(arg, arg_iter.next()) { Some(v) => sqlite_path = Some(v), None => return },
+            "--gantt-vs" => match option_value(arg, arg_iter.next()) { Some(v) => gantt_vs = Some(v), None => return },
+            "--events" => match option_value(arg, arg_iter.next()) { Some(v) => events_path = Some(v), None => return },
+            "--csv" => match option_value(arg, arg_iter.next()) { Some(v) => csv_path = Some(v), None => return },
+            "--annotate-csv" => annotate_csv = true,
+            "--vcd" => match option_value(arg, arg_iter.next()) { Some(v) => vcd_path = Some(v), None => return },
+            "--replay-gantt" => match option_value(arg, arg_iter.next()) { Some(v) => replay_path = Some(v), None => return },
+            "--live" => match option_value(arg, arg_iter.next()) { Some(v) => live_path = Some(v), None => return },
+            "--checkpoint" => match option_value(arg, arg_iter.next()) { Some(v) => checkpoint_path = Some(v), None => return },
+            "--resume" => match option_value(arg, arg_iter.next()) { Some(v) => resume_path = Some(v), None => return },
+            "--stop-at" => match option_value(arg, arg_iter.next()) { Some(v) => stop_at = Some(v), None => return },
+            "--checkpoint-every" => {
+                checkpoint_every = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(every) if every > 0.0 => Some(every),
+                    _ => {
+                        println!("Error: --checkpoint-every needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--shuffle-ties" => match option_value(arg, arg_iter.next()) { Some(v) => shuffle_seed = Some(v), None => return },
+            "--model" => match option_value(arg, arg_iter.next()) { Some(v) => model = v, None => return },
+            "--seed" => match option_value(arg, arg_iter.next()) { Some(v) => seed = v, None => return },
+            "--max-gap" => {
+                max_gap = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(n) if n >= 0 => n,
+                    _ => {
+                        println!("Error: --max-gap needs a whole number that is 0 or more");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--max-duration" => {
+                max_duration = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(n) if n >= 1 => n,
+                    _ => {
+                        println!("Error: --max-duration needs a whole number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--jitter" => match option_value(arg, arg_iter.next()) { Some(v) => jitter = v, None => return },
+            "--lambda" => {
+                lambda = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(n) if n > 0.0 => n,
+                    _ => {
+                        println!("Error: --lambda needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            "--mean-service" => {
+                mean_service = match arg_iter.next().and_then(|v| v.parse().ok()) {
+                    Some(n) if n > 0.0 => n,
+                    _ => {
+                        println!("Error: --mean-service needs a number greater than 0");
+                        usage();
+                        return;
+                    }
+                };
+            }
+            _ if arg.starts_with("--") => {
+                println!("Error: Unknown option: {arg}");
+                usage();
+                return;
+            }
+            _ => positional.push(arg),
+        }
+    }
+    if positional.len() == 1 && positional[0] == "selftest" {
+        // exit with a non-zero code on failure so scripts can tell it failed
+        if !selftest() {
+            std::process::exit(1);
+        }
+        return;
+    }
+    if fairness && positional.len() == 1 {
+        // the table would be buried under mlfq's state printing
+        config.mlfq_printing = false;
+        match read_workload(positional[0], arrivals) {
+            Ok(wkld) => compare_fairness(&wkld, &config, &only),
+            Err(e) => println!("Error: {e}"),
+        }
+        return;
+    }
+    if vs_optimal && positional.len() == 1 {
+        config.mlfq_printing = false;
+        match read_workload(positional[0], arrivals) {
+            Ok(wkld) => compare_optimal(&wkld, &config, &only),
+            Err(e) => println!("Error: {e}"),
+        }
+        return;
+    }
+    if let Some((start, end, step)) = sweep {
+        if positional.len() != 1 {
+            usage();
+            return;
+        }
+        match read_workload(positional[0], arrivals) {
+            Ok(wkld) => sweep_quantum(&wkld, &config, start, end, step),
+            Err(e) => println!("Error: {e}"),
+        }
+        return;
+    }
+    if positional.len() == 2 && positional[0] == "all" {
+        config.mlfq_printing = false;
+        match read_workload(positional[1], arrivals) {
+            Ok(wkld) => compare_all(&wkld, &config, &only),
+            Err(e) => println!("Error: {e}"),
+        }
+        return;
+    }
+    // convert reads a workload in one format and writes it in another, the formats come from the file extensions
+    if positional.len() == 3 && positional[0] == "convert" {
+        let wkld = match read_workload(positional[1], arrivals) {
+            Ok(wkld) => wkld,
+            Err(e) => {
+                println!("Error: {e}");
+                return;
+            }
+        };
+        if let Err(e) = write_workload(positional[2], &wkld) {
+            println!("Error: could not write {}: {e}", positional[2]);
+        }
+        return;
+    }
+    if positional.len() != 2 {
+        usage();
+        return;
+    }
+
+    // gen prints a generated workload instead of running an algorithm, redirect it into a file to use it
+    if positional[0] == "gen" {
+        let n: usize = match option_value("gen", Some(positional[1])) { Some(n) => n, None => return };
+        if jitter < 0 || (jitter > 0 && model != "uniform") {
+            println!("Error: --jitter needs a whole number of at least 0, and only works with the uniform model");
+            usage();
+            return;
+        }
+        let wkld = match model.as_str() {
+            "uniform" => generate_workload(n, max_gap, max_duration, jitter, seed),
+            "poisson" => generate_poisson_workload(n, lambda, mean_service, seed),
+            "md1" => generate_md1_workload(n, lambda, mean_service, seed),
+            _ => {
+                println!("Error: Unknown model: {model}");
+                usage();
+                return;
+            }
+        };
+        print_workload(&wkld);
         return;
     }
 
-    let algo: &String = &args[1]; // This is a reference to the second String in the args vector, a borrow of the value
-    let wkld_path: &String = &args[2];
+    let algo: &String = positional[0]; // This is a reference to a String in the args vector, a borrow of the value
+    let wkld_path: &String = positional[1];
 
-    let wkld = read_workload(&wkld_path);
+    if stream {
+        if algo != "fifo" || wkld_path.ends_with(".json") || wkld_path.ends_with(".bin") {
+            println!("Error: --stream only works with fifo on a whitespace format workload");
+            return;
+        }
+        run_streaming_fifo(wkld_path, arrivals, relative);
+        return;
+    }
 
-    match algo.as_str() { // switch statement equivalent
-        "fifo" => show_metrics(&fifo(&wkld)),
-        "sjf" => show_metrics(&sjf(&wkld)),
-        "stcf" => show_metrics(&stcf(&wkld)),
-        "rr" => show_metrics(&rr(&wkld)),
-        "mlfq" => show_metrics(&mlfq(&wkld)),
-        _ => {
-            println!("Error: Unknown algorithm:");
-            println!("usage: cargo run -- [fifo|sjf|stcf|rr|mlfq] workload_file");
+    let mut wkld = if lenient {
+        // every bad line gets reported and skipped, the algorithm runs on whatever is left
+        let (wkld, errors) = read_workload_lenient(wkld_path, arrivals);
+        for e in &errors {
+            println!("Warning: {e}");
+        }
+        if wkld.is_empty() {
+            return;
+        }
+        wkld
+    } else {
+        match read_workload(wkld_path, arrivals) {
+            Ok(wkld) => wkld,
+            Err(e) => {
+                println!("Error: {e}");
+                return;
+            }
+        }
+    };
+    if let Some(seed) = shuffle_seed {
+        shuffle_ties(&mut wkld, seed);
+    }
+    if config.on_miss.is_some() && algo != "edf" {
+        println!("Error: --abort-on-miss only works with edf");
+        return;
+    }
+    if config.stream_output && algo != "stcf" && algo != "rr" && algo != "mlfq" {
+        println!("Error: --stream-output only works with stcf, rr and mlfq");
+        return;
+    }
+    if config.partial_ticks && algo != "stcf" && algo != "rr" && algo != "mlfq" {
+        println!("Error: --partial-ticks only works with stcf, rr and mlfq");
+        return;
+    }
+    if config.preempt_on_arrival && algo != "stcf" && algo != "rr" {
+        println!("Error: --preempt-on-arrival only works with stcf and rr");
+        return;
+    }
+    if core_trace && algo != "gang" && algo != "rr-multi" {
+        println!("Error: --core-trace only works with gang and rr-multi, the algorithms that simulate more than one CPU");
+        return;
+    }
+    // suspend and resume events are only simulated by rr
+    if let Some(path) = &events_path {
+        if algo != "rr" {
+            println!("Error: --events only works with rr");
+            return;
+        }
+        config.events = match read_events(path) {
+            Ok(events) => events,
+            Err(e) => {
+                println!("Error: {e}");
+                return;
+            }
+        };
+    }
+    if periodic {
+        let tasks = wkld.iter().filter(|p| p.period.is_some()).count();
+        if let Some(end) = hyperperiod(&wkld) {
+            wkld = expand_periodic(&wkld);
+            let jobs = wkld.iter().filter(|p| p.task.is_some()).count();
+            println!("Expanded {tasks} periodic tasks into {jobs} jobs over the hyperperiod {end}");
         }
     }
+    if let Err(e) = check_time_range(&wkld, &config) {
+        println!("Error: {e}");
+        return;
+    }
+    // live arrivals are only read by rr, and a pipe can only be read once so there is nothing to repeat
+    let mut live = match &live_path {
+        Some(_) if algo != "rr" => {
+            println!("Error: --live only works with rr");
+            return;
+        }
+        Some(_) if repeat.is_some() => {
+            println!("Error: --live can't be used with --repeat");
+            return;
+        }
+        // the perturbed run would be missing the live processes
+        Some(_) if sensitivity.is_some() => {
+            println!("Error: --live can't be used with --sensitivity");
+            return;
+        }
+        Some(path) => match File::open(path) {
+            Ok(file) => Some(LiveArrivals::new(BufReader::new(file), wkld.len())),
+            Err(e) => {
+                println!("Error: could not open {path}: {e}");
+                return;
+            }
+        },
+        None => None,
+    };
+    // only rr can be checkpointed, and a run that reads a pipe or runs more than once can't be saved as one state
+    if (checkpoint_path.is_some() || resume_path.is_some()) && (algo != "rr" || live.is_some() || repeat.is_some()) {
+        println!("Error: --checkpoint and --resume only work with rr, and not with --live or --repeat");
+        return;
+    }
+    if checkpoint_path.is_none() && (checkpoint_every.is_some() || stop_at.is_some()) {
+        println!("Error: --checkpoint-every and --stop-at need --checkpoint");
+        return;
+    }
+    if annotate_csv && csv_path.is_none() {
+        println!("Error: --annotate-csv needs --csv");
+        return;
+    }
+    let checkpointing = checkpoint_path.map(|path| Checkpointing { path, every: checkpoint_every.unwrap_or(f32::INFINITY), stop_at });
+    // this is about the workload, not the schedule, so it goes before the algorithm runs
+    if histogram {
+        show_duration_histogram(&wkld);
+    }
+    // one line per run is the whole point of --format line, so it is quiet too
+    if quiet || line_format {
+        config.mlfq_printing = false;
+    }
+    // a resumed run takes its quantum from the checkpoint, and the run it resumes may not have printed this, so it is left
+    // out of both parts of a checkpointed run
+    if algo == "rr" && !quantum_given && checkpointing.is_none() && resume_path.is_none() && !quiet && !line_format {
+        println!("Note: no --quantum given, so rr uses {}. Half the median duration, {}, would suit this workload", config.quantum, suggest_rr_quantum(&wkld));
+    }
+    // a quantum at least as long as every process means nothing is ever preempted, easy to miss when reading the results
+    if algo == "rr" && !quiet && !line_format && wkld.iter().all(|p| p.duration <= config.quantum) {
+        println!("Note: the quantum {} is at least as long as every process, RR with this quantum behaves like FIFO", config.quantum);
+    }
+    // a slower CPU or dispatch overhead makes processes take longer than their duration, so they could overlap after all
+    let default_timing = config.speed == 1.0 && config.dispatch_overhead == 0.0;
+    if !quiet && !line_format && default_timing && wkld.len() > 1 && is_fifo_equivalent_workload(&wkld) {
+        println!("Note: every process arrives after the one before it completes, so every algorithm gives this workload the same schedule as FIFO");
+    }
+    // with no spread in the arrivals, response time is only how far down the queue a process starts, easy to read as more
+    if !quiet && !line_format && wkld.len() > 1 && wkld.iter().all(|p| p.arrival == wkld[0].arrival) {
+        println!("Warning: all processes arrive simultaneously; response times reflect queue position only");
+    }
+
+    install_interrupt_handler();
+
+    // with --repeat the algorithm runs N times and each run is timed, the algorithms are deterministic so every run
+    // gives the same schedule and only the last one is kept for printing
+    let mut times: Vec<Duration> = Vec::new();
+    let mut result = None;
+    for _ in 0..repeat.unwrap_or(1) {
+        let start = Instant::now();
+        result = match (&mut live, &resume_path, &checkpointing) {
+            (Some(live), _, _) => Some(rr_live(&wkld, &config, live)),
+            (None, Some(path), _) => match resume_rr(&wkld, path, &config, checkpointing.as_ref()) {
+                Ok(result) => Some(result),
+                Err(e) => {
+                    println!("Error: {e}");
+                    return;
+                }
+            },
+            (None, None, Some(checkpointing)) => Some(rr_checkpointable(&wkld, &config, checkpointing)),
+            (None, None, None) => run_algorithm(algo, &wkld, &config),
+        };
+        times.push(start.elapsed());
+    }
+    let result = match result {
+        Some(result) => result,
+        None => {
+            println!("Error: Unknown algorithm:");
+            usage();
+            return;
+        }
+    };
 
-    return;
- }
\ No newline at end of file
+    if line_format {
+        // just the workload's file name, the directories it is in would only get in the way of grep
+        let name = Path::new(wkld_path).file_name().map_or(wkld_path.clone(), |n| n.to_string_lossy().to_string());
+        show_summary_line(algo, &name, &result.processes);
+    } else {
+        show_metrics(&result.processes, relative, top_n);
+    }
+    if conservation {
+        // stcf, rr, mlfq and rr-multi run in steps of --tick at --speed, the other tick based ones in steps of 1, at --speed if
+        // they take a SimConfig and at speed 1 if not, and the rest run every process for exactly its duration
+        let (speed, tick) = match algo.as_str() {
+            // with --partial-ticks or --preempt-on-arrival steps end where the work does, nothing overshoots
+            "stcf" | "rr" | "mlfq" if config.partial_ticks => (config.speed, 0.0),
+            "stcf" | "rr" if config.preempt_on_arrival => (config.speed, 0.0),
+            "stcf" | "rr" | "mlfq" | "rr-multi" => (config.speed, config.tick),
+            "two-level" | "cfs" | "edf" | "hybrid" | "aging" | "rr-classed" => (config.speed, 1.0),
+            _ => (1.0, 0.0),
+        };
+        match check_conservation(&result, speed, tick) {
+            Ok(()) => println!("Conservation: OK"),
+            Err(problem) => {
+                println!("Error: conservation check failed, {problem}");
+                std::process::exit(1);
+            }
+        }
+    }
+    if queueing_delay {
+        println!("Average Queueing Delay:  {}", avg_queueing_delay(&result));
+    }
+    // next to the makespan, how far it is from the best any number of CPUs could do
+    if critical {
+        println!("Critical Path:           {}", critical_path(&wkld));
+    }
+    // rho is about the workload, so it is the same whatever the algorithm
+    if load {
+        match offered_load(&wkld) {
+            Some(rho) => {
+                println!("Offered Load (rho):      {rho}");
+                if rho >= 1.0 {
+                    println!("Warning: rho is at least 1, work arrives at least as fast as the CPU can do it, so the ready queue grows without bound");
+                }
+            }
+            None => println!("Offered Load (rho):      undefined (every process arrives at the same time)"),
+        }
+    }
+    // only the algorithms that charge the dispatch overhead, the others ignore it. rr-multi charges it too, but on
+    // every CPU at once, so its overhead divided by one makespan isn't a share of anything
+    if config.dispatch_overhead > 0.0 && matches!(algo.as_str(), "stcf" | "rr" | "mlfq" | "two-level" | "cfs" | "edf" | "hybrid" | "aging" | "rr-classed") && !result.processes.is_empty() {
+        let (overhead, ratio) = overhead_ratio(&result.segments, config.dispatch_overhead, &result.processes);
+        println!("Overhead Ratio:          {ratio} ({overhead} of the makespan {} was dispatch overhead)", makespan(&result.processes));
+    }
+    if weighted {
+        println!("Weighted Completion Time: {}", weighted_completion(&result.processes));
+    }
+    if completion_cv {
+        match completion_gap_cv(&result.processes) {
+            Some(cv) => println!("Completion Gap CV:       {cv}"),
+            None => println!("Completion Gap CV:       undefined (fewer than 2 completions, or all at the same time)"),
+        }
+    }
+    if response_var {
+        println!("Response Time Variance:  {}", response_variance(&result.processes));
+    }
+    if interactive && !result.processes.is_empty() {
+        println!("Max Response Time:       {}", max_response(&result.processes));
+        println!("Min Response Time:       {}", min_response(&result.processes));
+    }
+    if energy {
+        println!("Energy:                  {}", estimate_energy(&result.processes, ACTIVEPOWER, IDLEPOWER));
+    }
+    show_incomplete(&result.incomplete);
+    show_budgets(&result.processes);
+    // only rr-multi looks at the affinity, for the others every process would show 0 violations
+    if algo == "rr-multi" {
+        show_affinity_violations(&result.processes);
+    }
+    if algo == "spn" {
+        println!("Average Prediction Error: {}", avg_prediction_error(&wkld, SPNALPHA));
+    }
+    if dispatch_order {
+        show_dispatch_order(&result.segments);
+    }
+    if core_trace {
+        show_core_trace(&result.segments, &result.segment_cpus, config.cpus);
+    }
+    for t in &at_times {
+        match process_at_time(&result.segments, *t) {
+            Some(pid) => println!("At t={t}: pid {pid}"),
+            None => println!("At t={t}: idle"),
+        }
+    }
+    if spans {
+        show_spans(&result.processes, &result.segments);
+    }
+    if preemptions {
+        show_preemptions(&result.segments);
+    }
+    if queue_stats {
+        show_queue_stats(&result.queue_lengths);
+    }
+    if footprint {
+        let (peak, time) = peak_footprint(&result);
+        println!("Peak Footprint:          {peak} (at t={time})");
+    }
+    if deadlines {
+        show_deadlines(&result.processes);
+    }
+    if periodic {
+        show_task_misses(&result.processes);
+    }
+    if let Some(threshold) = starvation_threshold {
+        show_starvation(&result.processes, threshold);
+    }
+    if let Some(width) = bucket_width {
+        show_arrival_buckets(&result.processes, width);
+    }
+    if let Some(width) = window_width {
+        show_window_turnarounds(&result.processes, width);
+    }
+    if vs_sjf {
+        // how much longer the average turnaround was than sjf's on the same workload, as a percent of sjf's
+        let sjf_turnaround = avg_turnaround(&sjf(&wkld).processes);
+        let difference = (avg_turnaround(&result.processes) - sjf_turnaround) / sjf_turnaround * 100.0;
+        println!("Turnaround vs SJF:       {difference:+.1}% (SJF average turnaround {sjf_turnaround})");
+    }
+    if vs_ps {
+        // processor sharing is the ideal RR is trying to get close to, so every process gets compared
+        let ps = processor_sharing(&wkld);
+        let ps_turnaround = avg_turnaround(&ps);
+        let difference = (avg_turnaround(&result.processes) - ps_turnaround) / ps_turnaround * 100.0;
+        println!("Turnaround vs PS:        {difference:+.1}% (PS average turnaround {ps_turnaround})");
+        for p in &result.processes {
+            let ideal = ps.iter().find(|q| q.pid == p.pid).unwrap();
+            println!("\tpid={}, completion={}, ps_completion={}", p.pid, p.completion, ideal.completion);
+        }
+    }
+    if let Some((pid, delta)) = sensitivity {
+        // the same run again with one process longer (or shorter), everything else the same
+        let quiet_config = SimConfig { mlfq_printing: false, ..config.clone() };
+        match perturb_duration(&wkld, pid, delta) {
+            Some(perturbed) => show_sensitivity(&result.processes, &run_algorithm(algo, &perturbed, &quiet_config).unwrap().processes, pid, delta),
+            None => println!("Error: --sensitivity: there is no pid {pid}, or {delta} would make its duration negative"),
+        }
+    }
+    if gantt {
+        show_gantt(&gantt_segments(&result), gantt_scale);
+    }
+    if gantt_json {
+        show_gantt_json(&gantt_segments(&result));
+    }
+    if let Some(path) = &vcd_path {
+        if let Err(e) = export_vcd(&result.segments, path) {
+            println!("Error: could not write {path}: {e}");
+        }
+    }
+    if let Some(path) = &replay_path {
+        match std::fs::read_to_string(path).map_err(|e| e.to_string()).and_then(|text| parse_gantt_json(&text)) {
+            Ok(gantt) => show_gantt_replay(&metrics_from_segments(&gantt, &wkld), &result.processes),
+            Err(e) => println!("Error: could not read the Gantt chart {path}: {e}"),
+        }
+    }
+    if let Some(other) = &gantt_vs {
+        // the other algorithm's mlfq state would end up in the middle of the output
+        let other_config = SimConfig { mlfq_printing: false, ..config.clone() };
+        match run_algorithm(other, &wkld, &other_config) {
+            Some(other_result) => render_comparison_gantt(&gantt_segments(&result), &gantt_segments(&other_result),
+                [algo, other], gantt_scale),
+            None => println!("Error: Unknown algorithm: {other}"),
+        }
+    }
+    if inversion {
+        println!("Priority Inversion Time: {}", priority_inversion(&result.segments, &result.processes, &result.incomplete));
+    }
+    if fingerprint {
+        // {:016x} prints the number in hexadecimal, padded to 16 digits
+        println!("Schedule Fingerprint: {:016x}", schedule_fingerprint(&result.processes));
+    }
+    if let Some(path) = &csv_path {
+        if let Err(e) = write_csv(path, &result.processes, annotate_csv) {
+            println!("Error: could not write {path}: {e}");
+        }
+    }
+    if let Some(db_path) = sqlite_path {
+        write_results_db(&db_path, algo, wkld_path, &result.processes);
+    }
+    if let Some(n) = repeat {
+        let min = times.iter().min().unwrap();
+        let max = times.iter().max().unwrap();
+        let mean = times.iter().sum::<Duration>() / n as u32;
+        println!("Timing over {n} runs: min={min:?} mean={mean:?} max={max:?}");
+    }
+}
@@ -1,27 +1,243 @@
 // using namespace of standard library (std) with specific modules (io, fs, cmp, collections) 
 // and crate dependencies (num_traits, binary_heap_plus)
 use std::io;
-use std::io::BufRead;
+use std::io::{BufRead, Read};
 use std::fs::File;
 use std::cmp::Ordering;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 use num_traits::cast::ToPrimitive;
-use binary_heap_plus;
 
 // constants declaration, edit these to change the behavior of MLFQ
 const BOOSTTIME: i32 = 10; // changes boost time, how long it takes before all processes are boosted to the first level queue
 const MLFQPRINTING: bool = true; // true to print MLFQ state, false to disable printing
 
+// constants for the ASCII charts
+const GANTTTICK: f32 = 5.0; // how many columns apart the labels on the Gantt chart's time axis are, at least
+const HISTOGRAMBUCKETS: f32 = 5.0; // the most buckets the duration histogram splits the durations into
+// the ANSI terminal colors the comparison Gantt chart gives processes, pid 0 the first, pid 1 the second and so on
+// (going round again after the last), like pid N always being the same color on a slide
+const PIDCOLORS: [&str; 6] = ["\x1b[31m", "\x1b[32m", "\x1b[33m", "\x1b[34m", "\x1b[35m", "\x1b[36m"];
+const RESETCOLOR: &str = "\x1b[0m";
+// VCD times are whole numbers, a tick is this many of them (the file's timescale is 1 ms, so a tick reads as a second)
+const VCDSCALE: f32 = 1000.0;
+
+// times are f32, which holds every whole number only up to 2^24. Past it curr_time + 1.0 rounds back to curr_time, so
+// a tick loop would never get anywhere, and completion times would be off anyway
+pub const MAXEXACTTIME: f32 = 16777216.0;
+
+// decides when MLFQ moves a process down a level. This is an enum, which is like a C++ enum except each variant can
+// also hold data, here Allotment holds how much time a process gets at a level
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum DemotionPolicy {
+    // demote a process whenever it uses its whole time slice. The slice is 1 tick, so this is every time it runs
+    #[default]
+    FullSlice,
+    // demote a process once it has used this much CPU time at its current level in total, no matter how many
+    // slices that took (this is the rule from OSTEP that stops a process from gaming the scheduler)
+    Allotment(f32),
+}
+
+impl DemotionPolicy {
+    // true if a process that has used `used` time at its current level should move down
+    fn demotes(&self, used: f32) -> bool {
+        match self {
+            // a slice is 1, with a --tick under 1 it takes a few steps to use it all
+            DemotionPolicy::FullSlice => used >= 1.0,
+            DemotionPolicy::Allotment(allotment) => used >= *allotment,
+        }
+    }
+}
+
+// decides where MLFQ's boost (every BOOSTTIME ticks) moves processes to
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum BoostPolicy {
+    // every process goes back to the first level
+    #[default]
+    ToTop,
+    // every process below the first level moves up exactly one level, like some textbooks describe it
+    OneLevel,
+}
+
+// what edf does with "--abort-on-miss" once a process can't complete by its deadline anymore, even if it got the CPU
+// for every tick from now on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MissAction {
+    // the task set is infeasible, stop the whole simulation there
+    Stop,
+    // only that job has failed, drop it and schedule the rest
+    Skip,
+}
+
+// how mlfq prints its state every tick (when MLFQPRINTING is on)
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum MlfqFormat {
+    // one line per tick with the length of each level, like "t=5 [2,1,0,0]"
+    #[default]
+    Compact,
+    // every level's full contents every tick
+    Verbose,
+}
+
+// settings for the algorithms, mostly the tick based ones (stcf, rr, mlfq), grouped in one struct so adding a new setting doesn't
+// mean adding another argument to every algorithm. SimConfig::default() gives the original behavior
+#[derive(Clone, Debug)]
+pub struct SimConfig {
+    pub speed: f32, // how much work the CPU does per tick, 0.5 means a duration 10 process needs 20 ticks
+    pub tick: f32, // how long one step of stcf, rr, mlfq and rr-multi is, 0.5 simulates in half ticks so durations like 2.5 fit exactly
+    pub quantum: f32, // only used by rr, how many ticks a process runs before going to the back of the queue
+    // time the scheduler spends every time it puts a process on the CPU (the algorithms that take a SimConfig). The
+    // time slice ones pay it every slice, even when the same process runs again. stcf, edf and aging decide every tick,
+    // so they only pay it when a process takes over from a different one or from idle (see is_new_dispatch)
+    pub dispatch_overhead: f32,
+    pub demotion: DemotionPolicy, // only used by mlfq
+    pub boost: BoostPolicy, // only used by mlfq
+    pub mlfq_format: MlfqFormat, // only used by mlfq
+    pub mlfq_printing: bool, // only used by mlfq, starts as MLFQPRINTING but can be turned off for things like the selftest
+    pub running_average: bool, // print the average turnaround so far every time a process completes
+    pub stream_output: bool, // print every process the moment it completes, during the simulation (stcf, rr, mlfq)
+    pub cpus: usize, // only used by gang and rr-multi, how many CPUs the machine has
+    pub affinity_wait: f32, // only used by rr-multi, how long a process waits for its affinity CPU before taking any free one
+    pub max_in_memory: usize, // only used by two_level, how many processes fit in memory at once
+    pub min_granularity: f32, // only used by cfs, how long a process runs before cfs looks for one with less vruntime
+    pub window: f32, // only used by hybrid, how many ticks it uses one policy before switching to the other
+    pub aging_rate: f32, // only used by aging, how much a process's priority number goes down for every tick it waits
+    pub classes: usize, // only used by rr-classed, how many priority classes it keeps a round robin queue for
+    pub total_memory: f32, // only used by memory, how much memory the admitted processes can hold between them
+    pub on_miss: Option<MissAction>, // only used by edf, None keeps running processes that are sure to miss their deadline
+    pub events: Vec<SuspendEvent>, // only used by rr, suspend and resume events from an --events file, sorted by time
+    pub preempt_on_arrival: bool, // only used by stcf and rr, end a step early at an arrival or when the process completes (see step_length)
+    pub partial_ticks: bool, // only used by stcf, rr and mlfq, end a step early when the process completes (see step_length)
+}
+
+// Default is a trait from the standard library for types that have an obvious starting value
+impl Default for SimConfig {
+    fn default() -> Self {
+        SimConfig {
+            speed: 1.0,
+            tick: 1.0,
+            quantum: 1.0,
+            dispatch_overhead: 0.0,
+            demotion: DemotionPolicy::default(),
+            boost: BoostPolicy::default(),
+            mlfq_format: MlfqFormat::default(),
+            mlfq_printing: MLFQPRINTING,
+            running_average: false,
+            stream_output: false,
+            cpus: 1,
+            affinity_wait: 5.0,
+            max_in_memory: 2,
+            min_granularity: 2.0,
+            window: 10.0,
+            aging_rate: 0.1,
+            classes: 3,
+            total_memory: 100.0,
+            on_miss: None,
+            events: Vec::new(),
+            preempt_on_arrival: false,
+            partial_ticks: false,
+        }
+    }
+}
+
+// one line of an --events file, something outside the scheduler suspending a process (taking it out of the ready
+// queue so it can't run) or resuming it (putting it back at the end of the queue) at a time
+#[derive(Clone, Debug, PartialEq)]
+pub struct SuspendEvent {
+    pub time: f32,
+    pub pid: usize,
+    pub suspend: bool, // false is resume
+}
+
+// what the first column of a workload file means
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum ArrivalFormat {
+    // the time the process arrives
+    #[default]
+    Absolute,
+    // the time since the process on the line before it arrived (the first line is since time 0), like some traces have
+    Delta,
+}
+
+// everything that can be wrong with a workload file, read_workload returns one of these instead of panicking so main
+// can print what is wrong and where. Line numbers start at 1, like in a text editor
+#[derive(Clone, Debug, PartialEq)]
+pub enum WorkloadError {
+    // the file couldn't be opened or read, holds the reason
+    Io(String),
+    // the file has no processes in it
+    Empty,
+    // a line has fewer than the 2 columns every process needs, arrival and duration
+    MissingColumn { line: usize },
+    // the arrival or duration on a line isn't a whole number
+    BadNumber { line: usize, token: String },
+    // a process can't need less than no time
+    NegativeDuration { line: usize, duration: i32 },
+    // one of the optional name=value columns is wrong, reason says how
+    BadColumn { line: usize, column: String, reason: String },
+    // a line arrives before the one above it, only fifo_streaming cares because it can't sort the file first
+    Unsorted { line: usize },
+    // a .bin workload isn't the size its count says, or has a process that can't be scheduled, reason says which
+    BadBinary(String),
+    // a .json workload isn't the shape parse_json_workload reads, position is the character it went wrong at
+    BadJson { position: usize, reason: String },
+}
+
+// the message main prints for each kind of error
+impl fmt::Display for WorkloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WorkloadError::Io(reason) => write!(f, "could not read the workload file: {reason}"),
+            WorkloadError::Empty => write!(f, "the workload file has no processes"),
+            WorkloadError::MissingColumn { line } => write!(f, "line {line}: needs an arrival and a duration"),
+            WorkloadError::BadNumber { line, token } => write!(f, "line {line}: {token} is not a whole number"),
+            WorkloadError::NegativeDuration { line, duration } => write!(f, "line {line}: duration {duration} is negative"),
+            WorkloadError::BadColumn { line, column, reason } => write!(f, "line {line}: {column}: {reason}"),
+            WorkloadError::Unsorted { line } => write!(f, "line {line}: arrives before the line above it, streaming needs the file sorted by arrival"),
+            WorkloadError::BadBinary(reason) => write!(f, "the binary workload {reason}"),
+            WorkloadError::BadJson { position, reason } => write!(f, "character {position} of the JSON workload: {reason}"),
+        }
+    }
+}
+
+// set by the ctrl-c handler in main. The preemptive algorithms check it every tick and stop early if it is set,
+// returning whatever finished so far. It is atomic because the handler runs outside the normal flow of the program
+pub static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+// constants declaration for SPN with prediction
+const SPNINITIAL: f32 = 5.0; // the guess for a process's first CPU burst, before there is any history to average
+
 // this is a struct with 2 trait derivations. Traits can be thought of as interfaces
 // I derive Clone here because I want to be able to use the .copy() method to make copies of a process
 // I derive Debug here as well, this is mostly for printing functionality
 #[derive(Clone, Debug)]
 pub struct Process {
+    pub pid:        usize, // process id, the line of the workload file the process came from, starting at 0
     pub arrival:    f32, // all values are 32-bit floating point numbers, also note here that everything has to be declared with pub
     pub duration:   f32, // class members and functions in other files by default are private
     pub first_run:  f32,
     pub completion: f32,
     pub remaining_time: f32,
+    pub bursts: Vec<f32>, // the CPU bursts that make up the duration, in order. A plain workload line is just one burst
+    pub priority: i32, // lower number means higher priority, like nice values in Linux. Everything is 0 unless the workload says otherwise
+    pub preemptible: bool, // false models a critical section, once on the CPU nothing can take it off until it gives it up itself
+    pub ready_wait: f32, // time spent waiting in the ready queue after first running, only the preemptive algorithms can make this more than 0
+    pub group: Option<usize>, // processes with the same group are one parallel job (a gang) for the gang algorithm, None is a job on its own
+    pub deadline: Option<f32>, // the time the process should have completed by, None if it doesn't have one
+    pub hard_deadline: bool, // true if missing the deadline makes the whole schedule a failure, false if it is only late (soft)
+    pub cpu_budget: Option<f32>, // only used by rr, the most CPU time the process is allowed, like a cgroup limit. None is no limit
+    pub budget_exhausted: bool, // set by rr when the process was completed because it used up its cpu_budget, not because it finished
+    pub yield_interval: Option<f32>, // only used by rr, the process gives up the CPU after running this long even if its quantum isn't up
+    pub affinity: Option<usize>, // only used by rr-multi, the CPU the process should run on, None runs anywhere
+    pub affinity_violations: usize, // set by rr-multi, how many time slices the process ran on a CPU other than its affinity
+    pub period: Option<f32>, // a periodic task arrives again every period, expand_periodic turns it into one process per arrival
+    pub task: Option<usize>, // set by expand_periodic, the pid of the periodic task this process is one job of
+    pub start_level: usize, // only used by mlfq, the level the process goes in when it arrives, 0 (the top) unless the workload says otherwise
+    pub depends_on: Option<usize>, // only used by fifo, the pid of an earlier process that has to complete before this one is ready
+    pub memory: f32, // only used by memory, how much memory the process holds from when it is admitted until it completes
+    pub weight: f32, // only used by smith, how much the process's completion time counts for, 1 unless the workload says otherwise
 }
 
 // impl means I am implementing the class itself
@@ -38,316 +254,3859 @@ impl Process {
     pub fn new<T>(arrival: T, duration: T, first_run: T, completion: T) -> Self 
         where T: ToPrimitive{
         Self {
+            pid: 0,
             arrival: ToPrimitive::to_f32(&arrival).unwrap(),
             duration: ToPrimitive::to_f32(&duration).unwrap(),
             first_run: ToPrimitive::to_f32(&first_run).unwrap(),
             completion: ToPrimitive::to_f32(&completion).unwrap(),
             remaining_time: ToPrimitive::to_f32(&duration).unwrap(),
+            bursts: vec![ToPrimitive::to_f32(&duration).unwrap()],
+            priority: 0,
+            preemptible: true,
+            ready_wait: 0.0,
+            group: None,
+            deadline: None,
+            hard_deadline: false,
+            cpu_budget: None,
+            budget_exhausted: false,
+            yield_interval: None,
+            affinity: None,
+            affinity_violations: 0,
+            period: None,
+            task: None,
+            start_level: 0,
+            depends_on: None,
+            memory: 0.0,
+            weight: 1.0,
+        }
+    }
+
+    // returns a copy of the process with only its arrival and duration kept, everything the simulator writes is cleared.
+    // Every algorithm calls this on its input so a workload that was already scheduled (for example the output of fifo
+    // fed back in) starts from a clean state instead of carrying a stale first_run, completion or remaining_time
+    pub fn reset(&self) -> Process {
+        Process {
+            first_run: 0.0,
+            completion: 0.0,
+            remaining_time: self.duration,
+            ready_wait: 0.0,
+            budget_exhausted: false,
+            affinity_violations: 0,
+            ..self.clone()
+        }
+    }
+}
+
+// Display is the trait println! uses for {}, where Debug is used for {:?}. Implementing it means any process can be
+// printed as one consistent line with println!("{}", p), the Debug output stays the full struct dump used by MLFQ
+impl fmt::Display for Process {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "pid={}, arrival={}, duration={}, first_run={}, completion={}",
+            self.pid, self.arrival, self.duration, self.first_run, self.completion
+        )
+    }
+}
+
+// this is what every algorithm returns. processes are the finished processes in the order they completed, and segments
+// is the run history, one (start, end, pid) tuple for each stretch of time a process spent on the CPU, in time order
+// incomplete is only filled when a simulation was interrupted, it holds the processes that never finished
+// queue_lengths is only filled by the tick based algorithms, one (time, length) tuple per tick with how many processes
+// were in the ready set (arrived, not complete, including the one about to run) at the start of that tick
+// segment_cpus is only filled by the multi CPU algorithms (gang and rr-multi), the CPU each segment ran on, in the same
+// order as segments
+#[derive(Clone, Debug)]
+pub struct ScheduleResult {
+    pub processes: Vec<Process>,
+    pub segments: Vec<(f32, f32, usize)>,
+    pub incomplete: Vec<Process>,
+    pub queue_lengths: Vec<(f32, usize)>,
+    pub segment_cpus: Vec<usize>,
+}
+
+// one bar of a Gantt chart, a stretch of time one process spent on the CPU. This is the same thing as a tuple in
+// ScheduleResult's segments, with names for the fields so code outside this file (like something drawing its own
+// chart) doesn't have to remember which number is which
+#[derive(Clone, Debug, PartialEq)]
+pub struct GanttSegment {
+    pub pid: usize,
+    pub start: f32,
+    pub end: f32,
+}
+
+impl GanttSegment {
+    // the segment as a JSON object, like {"pid": 0, "start": 0, "end": 2}
+    pub fn to_json(&self) -> String {
+        format!("{{\"pid\": {}, \"start\": {}, \"end\": {}}}", self.pid, self.start, self.end)
+    }
+}
+
+// the run history of a schedule as Gantt chart bars, in time order
+// input: borrowed ScheduleResult, output: Vector of GanttSegments
+pub fn gantt_segments(result: &ScheduleResult) -> Vec<GanttSegment> {
+    result.segments.iter().map(|&(start, end, pid)| GanttSegment { pid, start, end }).collect()
+}
+
+// fills in ready_wait for every process from the run history. On one CPU a process that has started but not completed
+// is either running or waiting in the ready queue, so every gap between two of its segments is time it spent waiting.
+// add_segment merges back to back segments, so a gap really is time some other process had the CPU
+// input: mutably borrowed Vector of Processes, borrowed run history, output: None
+fn record_ready_wait(processes: &mut Vec<Process>, segments: &Vec<(f32, f32, usize)>) {
+    for p in processes.iter_mut() {
+        let mut last_end: Option<f32> = None;
+        for &(start, end, _) in segments.iter().filter(|s| s.2 == p.pid) {
+            if let Some(last_end) = last_end {
+                p.ready_wait += start - last_end;
+            }
+            last_end = Some(end);
+        }
+    }
+}
+
+// does one step's worth of work on p (speed * tick). An amount that isn't exact as a float (like 0.3) leaves a rounding
+// error behind when it is taken away over and over, 3 - 10 * 0.3 comes out a tiny bit more than 0, so leftover work
+// under a thousandth of a step counts as done instead of costing a whole extra step
+fn run_tick(p: &mut Process, work: f32) {
+    p.remaining_time -= work;
+    if p.remaining_time < work * 1e-3 {
+        p.remaining_time = p.remaining_time.min(0.0);
+    }
+}
+
+// how long the next step of stcf, rr or mlfq is. Normally a whole tick, so a process with less than a tick of work left
+// overshoots to the end of the tick, and an arrival partway through a tick is only seen at the end of it. With
+// partial_ticks (or preempt_on_arrival) the step ends when p's work is done, so a process with a duration like 2.5
+// completes at exactly 2.5 ticks after it started. With preempt_on_arrival the step also ends at the next arrival if that
+// comes first, so the arrival is looked at (and can take the CPU in stcf) exactly when it arrives
+// input: borrowed SimConfig, borrowed running Process, current time, next arrival if there is one, output: f32
+fn step_length(config: &SimConfig, p: &Process, curr_time: f32, next_arrival: Option<f32>) -> f32 {
+    let mut step = config.tick;
+    if config.partial_ticks || config.preempt_on_arrival {
+        step = step.min(p.remaining_time / config.speed);
+    }
+    match next_arrival {
+        Some(arrival) if config.preempt_on_arrival && arrival > curr_time => step.min(arrival - curr_time),
+        _ => step,
+    }
+}
+
+// whether putting pid on the CPU at time is a new dispatch, it isn't if pid was already running right up until then.
+// stcf, edf and aging decide again every tick, and the process that keeps the CPU isn't put on it again
+fn is_new_dispatch(segments: &Vec<(f32, f32, usize)>, pid: usize, time: f32) -> bool {
+    segments.last().is_none_or(|s| s.2 != pid || s.1 != time)
+}
+
+// records that pid ran on the CPU from start to end. If that process was already running right up until start
+// (like RR putting a process straight back on the CPU when nothing else is ready) the last segment is extended instead
+fn add_segment(segments: &mut Vec<(f32, f32, usize)>, start: f32, end: f32, pid: usize) {
+    if let Some(last) = segments.last_mut() {
+        if last.2 == pid && last.1 == start {
+            last.1 = end;
+            return;
         }
     }
+    segments.push((start, end, pid));
 }
 
 //-----------UTILS----------
 
 // This function works similarly to the read_workload function in project 3, it takes in a path, and reads that file into a vector of processes
-pub fn read_workload(wkld_path: &String) -> Vec<Process>{
+// arrivals says whether the arrival column is an absolute time or a gap since the previous line
+// if anything is wrong with the file it returns the first problem as a WorkloadError instead
+pub fn read_workload(wkld_path: &String, arrivals: ArrivalFormat) -> Result<Vec<Process>, WorkloadError> {
     let mut wkld = Vec::new(); 
-    // notice unwrap here. Rust has this functionality where many things return a Result enum. The enum itself has 2 types, Ok(T) or Err(E).
-    // These are essentially wrappers around anything that could be returned. I have to call unwrap() to be able to extract that value 
-    // from the Result. This is important because it adds the ability to avoid having null types in the language. Read more by googling
-    // "null billion dollar mistake", the top results all explain a lot about the problems of having null references. 
-    let file = File::open(wkld_path).unwrap(); 
-    let lines = io::BufReader::new(file).lines(); // this function reads in a file to a buffered reader and returns an iterator over the file
-    for line in lines {
-        let l = line.unwrap();
-        // there are two things to notice in the next function, the |s| and expect. What this line does essentially is 
-        // take a line, split it on whitespaces into an iterator, and applies a map function, common in functional programming
-        // to then parse the string into an integer, then collecting the iterator into a vector.
-        // |s| indicates a closure, it is a an anonymous function which is Rust's version of a lambda function.
-        // expect works similary to unwrap(), except that unwrap calls a macro called panic! which essentially ends execution during runtime
-        // expect does not panic and instead can pass errors along.
-        let numbers: Vec<i32> = l.split_whitespace().map(|s| s.parse().expect("parse error")).collect();
-        let arrival = numbers[0];
-        let duration = numbers[1];
-        let p = Process::new(arrival,duration,0,0);
+    // notice the ? here. Rust has this functionality where many things return a Result enum. The enum itself has 2 types, Ok(T) or Err(E).
+    // These are essentially wrappers around anything that could be returned. I could call unwrap() to be able to extract that value 
+    // from the Result, but that ends the program on an Err. ? instead returns the Err from this function right away, and map_err turns
+    // the standard library's error into a WorkloadError first. This is important because it adds the ability to avoid having null types in the
+    // language. Read more by googling "null billion dollar mistake", the top results all explain a lot about the problems of having null references. 
+    let io_error = |e: io::Error| WorkloadError::Io(e.to_string());
+    let file = File::open(wkld_path).map_err(io_error)?; 
+    if wkld_path.ends_with(".json") {
+        // JSON workloads have their own parser, everything else is the whitespace format below
+        wkld = parse_json_workload(&io::read_to_string(file).map_err(io_error)?)?;
+    } else if wkld_path.ends_with(".bin") {
+        let mut bytes = Vec::new();
+        io::BufReader::new(file).read_to_end(&mut bytes).map_err(io_error)?;
+        wkld = parse_binary_workload(&bytes)?;
+    } else {
+        let lines = io::BufReader::new(file).lines(); // this function reads in a file to a buffered reader and returns an iterator over the file
+        for (pid, line) in lines.enumerate() {
+            wkld.push(parse_line(pid, &line.map_err(io_error)?)?);
+        }
+    }
+    if wkld.is_empty() {
+        return Err(WorkloadError::Empty);
+    }
+    order_workload(&mut wkld, arrivals);
+    Ok(wkld)
+}
+
+// like read_workload, but instead of stopping at the first bad line it skips every bad line and keeps going, so all of
+// a file's problems can be fixed in one pass. The processes keep the pid of their line, so skipped lines leave gaps
+// input: path, arrival format, output: (every Process that parsed, every WorkloadError in line order)
+pub fn read_workload_lenient(wkld_path: &String, arrivals: ArrivalFormat) -> (Vec<Process>, Vec<WorkloadError>) {
+    let mut wkld = Vec::new();
+    let mut errors = Vec::new();
+    if wkld_path.ends_with(".bin") {
+        // the binary format has no lines either, and isn't text so it can't be read as a string like the others
+        let read = std::fs::read(wkld_path).map_err(|e| WorkloadError::Io(e.to_string()));
+        match read.and_then(|bytes| parse_binary_workload(&bytes)) {
+            Ok(processes) => wkld = processes,
+            Err(e) => errors.push(e),
+        }
+    } else {
+        let contents = match std::fs::read_to_string(wkld_path) {
+            Ok(contents) => contents,
+            Err(e) => return (wkld, vec![WorkloadError::Io(e.to_string())]),
+        };
+        if wkld_path.ends_with(".json") {
+            // the JSON parser has no lines to skip, so it is all or nothing like in read_workload
+            match parse_json_workload(&contents) {
+                Ok(processes) => wkld = processes,
+                Err(e) => errors.push(e),
+            }
+        } else {
+            for (pid, line) in contents.lines().enumerate() {
+                match parse_line(pid, line) {
+                    Ok(p) => wkld.push(p),
+                    Err(e) => errors.push(e),
+                }
+            }
+        }
+    }
+    if wkld.is_empty() && errors.is_empty() {
+        errors.push(WorkloadError::Empty);
+    }
+    order_workload(&mut wkld, arrivals);
+    (wkld, errors)
+}
+
+// turns delta arrivals into absolute times and sorts the processes by arrival, the last step of reading a workload
+// input: mutably borrowed Vector of Processes in file order, arrival format, output: None
+fn order_workload(wkld: &mut Vec<Process>, arrivals: ArrivalFormat) {
+    // delta arrivals are added up in file order, before sorting mixes that order up
+    if arrivals == ArrivalFormat::Delta {
+        let mut time = 0.0;
+        for p in wkld.iter_mut() {
+            time += p.arrival;
+            p.arrival = time;
+        }
+    }
+    // sorting a vector by a key, the closure gets a borrowed process and returns what to sort it by. The closure's p is a
+    // borrow (&Process), so p.arrival reads the arrival without taking ownership of the process. There is a system of
+    // ownership within rust that only allows one owner of a piece of data on the heap, borrowing lets other code use it
+    // without consuming it. f32 can't be the key on its own because it has no total order (NaN isn't less than, equal to
+    // or greater than anything), OrderedF32 gives it one.
+    wkld.sort_by_key(|p| OrderedF32(p.arrival));
+}
+
+// reads an events file, one event per line written as "time pid suspend" or "time pid resume"
+// input: path, output: Result, the events sorted by time (events at the same time stay in file order) or what is
+// wrong with the file and on which line
+pub fn read_events(path: &String) -> Result<Vec<SuspendEvent>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("could not read the events file: {e}"))?;
+    let mut events = Vec::new();
+    for (i, l) in text.lines().enumerate() {
+        let columns: Vec<&str> = l.split_whitespace().collect();
+        if columns.len() != 3 {
+            return Err(format!("line {}: an event needs a time, a pid and suspend or resume", i + 1));
+        }
+        let time: f32 = columns[0].parse().map_err(|_| format!("line {}: {} is not a time", i + 1, columns[0]))?;
+        let pid: usize = columns[1].parse().map_err(|_| format!("line {}: {} is not a pid", i + 1, columns[1]))?;
+        let suspend = match columns[2] {
+            "suspend" => true,
+            "resume" => false,
+            other => return Err(format!("line {}: {other} is not suspend or resume", i + 1)),
+        };
+        events.push(SuspendEvent { time, pid, suspend });
+    }
+    events.sort_by_key(|e| OrderedF32(e.time));
+    Ok(events)
+}
+
+// parses one line of a whitespace format workload into the process with that pid (the line's index in the file)
+// input: pid, the line's text, output: Result, the Process or what is wrong with the line
+fn parse_line(pid: usize, l: &str) -> Result<Process, WorkloadError> {
+    let line = pid + 1;
+    // there are two things to notice in the next function, the |s| and map_err. What this line does essentially is 
+    // take a line, split it on whitespaces into an iterator, and applies a map function, common in functional programming
+    // to then parse the string into an integer, then collecting the iterator into a vector.
+    // |s| indicates a closure, it is a an anonymous function which is Rust's version of a lambda function.
+    // collecting an iterator of Results into a Result gives the first Err if there is one, so one bad number fails the line
+    let columns: Vec<&str> = l.split_whitespace().collect();
+    if columns.len() < 2 {
+        return Err(WorkloadError::MissingColumn { line });
+    }
+    let numbers: Vec<i32> = columns[..2].iter()
+        .map(|s| s.parse().map_err(|_| WorkloadError::BadNumber { line, token: s.to_string() }))
+        .collect::<Result<_, _>>()?;
+    let arrival = numbers[0];
+    let duration = numbers[1];
+    if duration < 0 {
+        return Err(WorkloadError::NegativeDuration { line, duration });
+    }
+    let mut p = Process::new(arrival,duration,0,0);
+    p.pid = pid;
+    // anything after the first 2 columns is an optional column written as name=value
+    for column in &columns[2..] {
+        parse_column(&mut p, column).map_err(|reason| WorkloadError::BadColumn { line, column: column.to_string(), reason })?;
+    }
+    Ok(p)
+}
+
+// parses a binary workload, the compact format for workloads too big to parse as text quickly: a little endian u32
+// with the number of processes, then for each process its arrival and its duration as little endian f32s. There are no
+// optional columns, and like the other formats the pid is the position in the file
+// input: the bytes of the file, output: Result, the Vector of Processes or what is wrong with the file
+fn parse_binary_workload(bytes: &[u8]) -> Result<Vec<Process>, WorkloadError> {
+    let (count, rest) = match bytes.split_first_chunk::<4>() {
+        Some((count, rest)) => (u32::from_le_bytes(*count) as usize, rest),
+        None => return Err(WorkloadError::BadBinary(format!("is {} bytes, too short to have a count", bytes.len()))),
+    };
+    if rest.len() != count * 8 {
+        return Err(WorkloadError::BadBinary(format!("says it has {count} processes but has {} bytes of them instead of {}", rest.len(), count * 8)));
+    }
+    let mut wkld = Vec::with_capacity(count);
+    for (pid, pair) in rest.chunks_exact(8).enumerate() {
+        let arrival = f32::from_le_bytes(pair[..4].try_into().unwrap());
+        let duration = f32::from_le_bytes(pair[4..].try_into().unwrap());
+        // NaN and infinity fail this
+        if !(arrival.is_finite() && duration >= 0.0 && duration.is_finite()) {
+            return Err(WorkloadError::BadBinary(format!("has process {pid} arriving at {arrival} with duration {duration}")));
+        }
+        let mut p = Process::new(arrival, duration, 0.0, 0.0);
+        p.pid = pid;
+        wkld.push(p);
+    }
+    Ok(wkld)
+}
+
+// parses a JSON workload, an array of objects like [{"arrival":0,"duration":5}, {"arrival":1,"duration":3,"priority":2}]
+// This is a small hand written parser for only that shape (values can be numbers, true/false, or arrays of numbers),
+// not all of JSON. Keys other than arrival and duration are the same optional columns the whitespace format has,
+// so they go through parse_column. A process's pid is its position in the array
+// anything wrong is a WorkloadError::BadJson, a missing or bad field points at the { of its object
+// input: the file's text, output: Vector of Processes, or the first problem
+fn parse_json_workload(text: &str) -> Result<Vec<Process>, WorkloadError> {
+    let mut parser = JsonParser { chars: text.chars().collect(), pos: 0 };
+    let mut wkld = Vec::new();
+    parser.expect('[')?;
+    while parser.peek() != Some(']') {
+        let start = parser.pos + 1;
+        let fields = parser.object()?;
+        let bad = |reason: String| WorkloadError::BadJson { position: start, reason };
+        let field = |name: &str| -> Result<f32, WorkloadError> {
+            let value = &fields.iter().find(|(key, _)| key == name).ok_or_else(|| bad(format!("the process has no {name}")))?.1;
+            value.parse().map_err(|_| bad(format!("{name} {value} is not a number")))
+        };
+        let mut p = Process::new(field("arrival")?, field("duration")?, 0.0, 0.0);
+        p.pid = wkld.len();
+        for (key, value) in fields.iter().filter(|(key, _)| key != "arrival" && key != "duration") {
+            parse_column(&mut p, &format!("{key}={value}")).map_err(|reason| bad(format!("{key}: {reason}")))?;
+        }
         wkld.push(p);
+        if parser.peek() == Some(',') {
+            parser.expect(',')?;
+        }
+    }
+    parser.expect(']')?;
+    Ok(wkld)
+}
+
+// keeps track of where parse_json_workload is in the text, every method skips whitespace before reading
+struct JsonParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl JsonParser {
+    // the next character that isn't whitespace, without moving past it
+    fn peek(&mut self) -> Option<char> {
+        while self.pos < self.chars.len() && self.chars[self.pos].is_whitespace() {
+            self.pos += 1;
+        }
+        self.chars.get(self.pos).copied()
+    }
+
+    // moves past c, or says what was there instead. Positions start at 1 like line numbers
+    fn expect(&mut self, c: char) -> Result<(), WorkloadError> {
+        match self.peek() {
+            Some(found) if found == c => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(found) => Err(WorkloadError::BadJson { position: self.pos + 1, reason: format!("expected '{c}' but found '{found}'") }),
+            None => Err(WorkloadError::BadJson { position: self.pos + 1, reason: format!("expected '{c}' but the file ended") }),
+        }
+    }
+
+    // reads {"key": value, ...} into (key, value) pairs, values are kept as text. An array value like [2,2,8]
+    // becomes "2,2,8", the same way the whitespace format writes it
+    fn object(&mut self) -> Result<Vec<(String, String)>, WorkloadError> {
+        let mut fields = Vec::new();
+        self.expect('{')?;
+        while self.peek() != Some('}') {
+            self.expect('"')?;
+            let key: String = self.take_while(|c| c != '"');
+            self.expect('"')?;
+            self.expect(':')?;
+            let value = if self.peek() == Some('[') {
+                self.expect('[')?;
+                let items = self.take_while(|c| c != ']');
+                self.expect(']')?;
+                items.split(',').map(|item| item.trim()).collect::<Vec<&str>>().join(",")
+            } else {
+                self.take_while(|c| c != ',' && c != '}').trim().to_string()
+            };
+            fields.push((key, value));
+            if self.peek() == Some(',') {
+                self.expect(',')?;
+            }
+        }
+        self.expect('}')?;
+        Ok(fields)
+    }
+
+    // reads characters until keep says to stop, the character it stops at is not read
+    fn take_while(&mut self, keep: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while self.pos < self.chars.len() && keep(self.chars[self.pos]) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+}
+
+// parses one optional name=value column of a workload line into the process it belongs to
+// input: mutable borrow of a Process, the column text, output: None
+fn parse_column(p: &mut Process, column: &str) -> Result<(), String> {
+    let (name, value) = column.split_once('=').ok_or("not written as name=value")?;
+    let bad_value = format!("{value} is not a valid {name}");
+    match name {
+        // bursts=3,4,3 splits the duration into CPU bursts, they have to add up to the duration
+        "bursts" => {
+            let bursts: Vec<f32> = value.split(',')
+                .map(|s| s.parse::<i32>().map(|b| b as f32).map_err(|_| format!("{s} is not a whole number")))
+                .collect::<Result<_, _>>()?;
+            if bursts.iter().sum::<f32>() != p.duration {
+                return Err(format!("bursts {value} do not add up to the duration {}", p.duration));
+            }
+            p.bursts = bursts;
+        }
+        "priority" => p.priority = value.parse().map_err(|_| bad_value)?,
+        "start_level" => p.start_level = value.parse().map_err(|_| bad_value)?,
+        "preemptible" => p.preemptible = value.parse().map_err(|_| bad_value)?,
+        "group" => p.group = Some(value.parse().map_err(|_| bad_value)?),
+        "deadline" => p.deadline = Some(value.parse::<i32>().map_err(|_| bad_value)? as f32),
+        "hard_deadline" => p.hard_deadline = value.parse().map_err(|_| bad_value)?,
+        "cpu_budget" => p.cpu_budget = Some(value.parse::<i32>().map_err(|_| bad_value)? as f32),
+        // only an earlier line, so a chain of dependencies can't loop back on itself
+        "depends_on" => match value.parse::<usize>() {
+            Ok(pid) if pid < p.pid => p.depends_on = Some(pid),
+            _ => return Err(format!("{value} is not the pid of an earlier line")),
+        },
+        "weight" => match value.parse::<i32>() {
+            Ok(weight) if weight > 0 => p.weight = weight as f32,
+            _ => return Err(format!("{value} is not a whole number greater than 0")),
+        },
+        "memory" => match value.parse::<i32>() {
+            Ok(memory) if memory >= 0 => p.memory = memory as f32,
+            _ => return Err(format!("{value} is not a whole number that is 0 or more")),
+        },
+        "affinity" => p.affinity = Some(value.parse().map_err(|_| bad_value)?),
+        "yield_interval" => match value.parse::<i32>() {
+            Ok(interval) if interval > 0 => p.yield_interval = Some(interval as f32),
+            _ => return Err(format!("{value} is not a whole number greater than 0")),
+        },
+        "period" => match value.parse::<i32>() {
+            Ok(period) if period > 0 => p.period = Some(period as f32),
+            _ => return Err(format!("{value} is not a whole number greater than 0")),
+        },
+        _ => return Err(format!("unknown workload column {name}")),
+    }
+    Ok(())
+}
+
+// predicts every CPU burst of a process by exponential averaging, estimate = alpha*last burst + (1-alpha)*last estimate
+// the first estimate is SPNINITIAL because there is no history yet
+// input: borrowed Vector of bursts, alpha, output: Vector with the estimate that was made before each burst
+pub fn predict_bursts(bursts: &Vec<f32>, alpha: f32) -> Vec<f32> {
+    let mut estimates = Vec::new();
+    let mut estimate = SPNINITIAL;
+    for burst in bursts {
+        estimates.push(estimate);
+        estimate = alpha * burst + (1.0 - alpha) * estimate;
+    }
+    estimates
+}
+
+// calculate average prediction error (|estimate - actual burst|) over every burst of every process
+// input: borrowed Vector of Processes, alpha, output: f32
+pub fn avg_prediction_error(processes: &Vec<Process>, alpha: f32) -> f32 {
+    let mut n = 0.0;
+    let mut sum = 0.0;
+    for p in processes {
+        for (estimate, burst) in predict_bursts(&p.bursts, alpha).iter().zip(p.bursts.iter()) {
+            sum += (estimate - burst).abs();
+            n += 1.0;
+        }
+    }
+    sum / n
+}
+
+// calculate average turnaround time (completion time - arrival time)
+// input: borrowed Vector of Processes, output: f32
+pub fn avg_turnaround(processes : &Vec<Process>) -> f32{
+    let n = processes.len() as f32; // note here the type declaration, .len() returns an integer, we convert it to f32 with as
+    let sum = processes.iter().fold(0.0, |acc, p| acc + p.completion - p.arrival); //similar to a reduce function
+    sum / n
+}
+
+// calculate average turnaround time (first run time - arrival time)
+// input: borrowed Vector of Processes, output: f32
+pub fn avg_response(processes : &Vec<Process>) -> f32 {
+    let n = processes.len() as f32;
+    let sum = processes.iter().fold(0.0, |acc, p| acc + p.first_run - p.arrival);
+    sum / n
+}
+
+// calculate the variance of the response times (the mean of the squared differences from avg_response). Two schedules
+// can have the same average response, the variance tells whether every process got a similar start or some started
+// right away and others waited a long time
+// input: borrowed Vector of Processes, output: f32
+pub fn response_variance(processes : &Vec<Process>) -> f32 {
+    let n = processes.len() as f32;
+    let mean = avg_response(processes);
+    let sum = processes.iter().fold(0.0, |acc, p| acc + (p.first_run - p.arrival - mean) * (p.first_run - p.arrival - mean));
+    sum / n
+}
+
+// calculate average queueing delay, the part of the turnaround a process wasn't being served (turnaround - duration).
+// This is the W_q of queueing theory, so arrival rate * average queueing delay is the average number of processes
+// waiting (Little's Law)
+// input: borrowed ScheduleResult, output: f32
+pub fn avg_queueing_delay(result: &ScheduleResult) -> f32 {
+    let n = result.processes.len() as f32;
+    let sum = result.processes.iter().fold(0.0, |acc, p| acc + p.completion - p.arrival - p.duration);
+    sum / n
+}
+
+// calculate the peak footprint, the most processes the scheduler ever had to hold at once (arrived and not completed,
+// the running one included), and the first time it had that many. Unlike the peak of --queue-stats this isn't sampled
+// once a tick, it comes from the arrival and completion of every process, so it is exact for every algorithm. It is
+// the capacity a fixed size ready queue would need. A process completing at the same time another arrives frees its
+// place first, and processes that were interrupted before completing never leave
+// input: borrowed ScheduleResult, output: (peak, time)
+pub fn peak_footprint(result: &ScheduleResult) -> (usize, f32) {
+    // +1 at every arrival, -1 at every completion. A process with no duration is never held
+    let mut changes: Vec<(f32, i32)> = Vec::new();
+    for p in result.processes.iter().filter(|p| p.completion > p.arrival) {
+        changes.push((p.arrival, 1));
+        changes.push((p.completion, -1));
+    }
+    changes.extend(result.incomplete.iter().map(|p| (p.arrival, 1)));
+    changes.sort_by(|a, b| OrderedF32(a.0).cmp(&OrderedF32(b.0)).then(a.1.cmp(&b.1)));
+    let mut held = 0;
+    let mut peak = (0, 0.0);
+    for (time, change) in changes {
+        held += change;
+        if held as usize > peak.0 {
+            peak = (held as usize, time);
+        }
+    }
+    peak
+}
+
+// calculate the longest response time, the worst case for how long a process waited before it first ran
+// input: borrowed Vector of Processes, output: f32
+pub fn max_response(processes: &Vec<Process>) -> f32 {
+    processes.iter().fold(f32::MIN, |acc, p| acc.max(p.first_run - p.arrival))
+}
+
+// calculate the shortest response time
+// input: borrowed Vector of Processes, output: f32
+pub fn min_response(processes: &Vec<Process>) -> f32 {
+    processes.iter().fold(f32::MAX, |acc, p| acc.min(p.first_run - p.arrival))
+}
+
+// calculate makespan, the time from the first arrival until the last completion (0 if there are no processes)
+// input: borrowed Vector of Processes, output: f32
+pub fn makespan(processes: &Vec<Process>) -> f32 {
+    if processes.is_empty() {
+        return 0.0;
+    }
+    let last_completion = processes.iter().fold(f32::MIN, |acc, p| acc.max(p.completion));
+    let first_arrival = processes.iter().fold(f32::MAX, |acc, p| acc.min(p.arrival));
+    last_completion - first_arrival
+}
+
+// calculate the offered load rho, the arrival rate times the mean service time. The arrival rate is the number of
+// processes over the time from the first arrival to the last, and the mean service time is the mean duration. At 1 or
+// more work arrives at least as fast as one CPU can do it, so the ready queue keeps growing the longer the workload is
+// input: borrowed Vector of Processes, output: Option of f32, None if every process arrives at the same time
+pub fn offered_load(workload: &Vec<Process>) -> Option<f32> {
+    let first_arrival = workload.iter().fold(f32::MAX, |acc, p| acc.min(p.arrival));
+    let last_arrival = workload.iter().fold(f32::MIN, |acc, p| acc.max(p.arrival));
+    if last_arrival <= first_arrival {
+        return None;
+    }
+    let n = workload.len() as f32;
+    let arrival_rate = n / (last_arrival - first_arrival);
+    let mean_service = workload.iter().map(|p| p.duration).sum::<f32>() / n;
+    Some(arrival_rate * mean_service)
+}
+
+// calculate the critical path, the least makespan the workload could have with as many CPUs as it needs: every process
+// starts as soon as it has arrived and what it depends_on has completed, so only the longest chain of dependencies
+// (and the arrivals) hold it back. A dependency on a pid that isn't in the workload is ignored, like fifo does
+// input: borrowed Vector of Processes, output: f32
+pub fn critical_path(workload: &Vec<Process>) -> f32 {
+    let mut order: Vec<&Process> = workload.iter().collect();
+    // a process only depends on an earlier pid, so in pid order its dependency is always done first
+    order.sort_by_key(|p| p.pid);
+    let mut completions: HashMap<usize, f32> = HashMap::new();
+    for p in order {
+        let ready = p.depends_on.and_then(|pid| completions.get(&pid)).map_or(p.arrival, |&done| done.max(p.arrival));
+        completions.insert(p.pid, ready + p.duration);
+    }
+    let last_completion = completions.values().fold(f32::MIN, |acc, &c| acc.max(c));
+    let first_arrival = workload.iter().fold(f32::MAX, |acc, p| acc.min(p.arrival));
+    last_completion - first_arrival
+}
+
+// suggests an rr quantum for the workload, half the median duration rounded to a whole number of ticks and at least 1.
+// A quantum a lot longer than most processes makes rr behave like FIFO and short processes wait behind long ones, one
+// a lot shorter switches so often that the dispatch overhead adds up. Half the median means most processes still get
+// preempted at least once, so they share the CPU, without the short ones being cut into many pieces
+// input: borrowed Vector of Processes, output: f32
+pub fn suggest_rr_quantum(workload: &Vec<Process>) -> f32 {
+    let mut durations: Vec<f32> = workload.iter().map(|p| p.duration).collect();
+    durations.sort_by_key(|&d| OrderedF32(d));
+    let mid = durations.len() / 2;
+    let median = if durations.len().is_multiple_of(2) { (durations[mid - 1] + durations[mid]) / 2.0 } else { durations[mid] };
+    (median / 2.0).round().max(1.0)
+}
+
+// checks whether the workload is one where the choice of algorithm can't matter: in arrival order, no process arrives
+// before the one ahead of it has completed, so there is never more than one process ready and every algorithm runs
+// each process from its arrival to its completion, the FIFO schedule. This is with the default settings, and
+// workloads with a group (gang waits for the whole gang), a cpu_budget (rr stops the process early) or a memory (it
+// might not fit in memory's total) don't count.
+// Processes that all have the same duration aren't enough on their own, that only makes the shortest_job algorithms
+// agree with FIFO while rr and mlfq still take turns
+// input: borrowed Vector of Processes, output: bool
+pub fn is_fifo_equivalent_workload(workload: &Vec<Process>) -> bool {
+    if workload.iter().any(|p| p.group.is_some() || p.cpu_budget.is_some() || p.memory != 0.0) {
+        return false;
+    }
+    let mut order: Vec<&Process> = workload.iter().collect();
+    order.sort_by_key(|p| OrderedF32(p.arrival));
+    // with nothing overlapping, each process completes at its arrival + duration
+    order.windows(2).all(|w| w[1].arrival >= w[0].arrival + w[0].duration)
+}
+
+// calculate Jain's fairness index of the turnaround times, (sum of x)^2 / (n * sum of x^2). It is 1 when every process
+// had the same turnaround and gets closer to 1/n the more one process's turnaround dominates
+// input: borrowed Vector of Processes, output: f32
+pub fn jain_fairness(processes: &Vec<Process>) -> f32 {
+    let turnarounds: Vec<f32> = processes.iter().map(|p| p.completion - p.arrival).collect();
+    let sum: f32 = turnarounds.iter().sum();
+    let sum_squares: f32 = turnarounds.iter().map(|t| t * t).sum();
+    if sum_squares == 0.0 {
+        return 1.0;
+    }
+    sum * sum / (turnarounds.len() as f32 * sum_squares)
+}
+
+// calculate how much of the makespan went to dispatch overhead instead of running processes. Nothing runs during the
+// overhead, so with any overhead at all every dispatch starts a new segment (add_segment can't join it to the one
+// before), and the total overhead is the number of segments times the overhead of one dispatch
+// input: borrowed Vector of run segments, dispatch overhead, borrowed Vector of Processes, output: (total overhead,
+// overhead divided by the makespan)
+pub fn overhead_ratio(segments: &Vec<(f32, f32, usize)>, dispatch_overhead: f32, processes: &Vec<Process>) -> (f32, f32) {
+    let overhead = segments.len() as f32 * dispatch_overhead;
+    (overhead, overhead / makespan(processes))
+}
+
+// calculate the coefficient of variation (standard deviation / mean) of the gaps between consecutive completions, how
+// steadily processes come out of the scheduler. 0 means they complete at an even rate, a higher value means they come
+// out in bursts with long quiet stretches in between
+// input: borrowed Vector of Processes, output: Option of f32, None with fewer than 2 completions, or if they were all at once
+pub fn completion_gap_cv(processes: &Vec<Process>) -> Option<f32> {
+    let mut completions: Vec<f32> = processes.iter().map(|p| p.completion).collect();
+    completions.sort_by_key(|&c| OrderedF32(c));
+    let gaps: Vec<f32> = completions.windows(2).map(|w| w[1] - w[0]).collect();
+    let n = gaps.len() as f32;
+    let mean = gaps.iter().sum::<f32>() / n;
+    if gaps.is_empty() || mean == 0.0 {
+        return None;
+    }
+    let variance = gaps.iter().map(|g| (g - mean) * (g - mean)).sum::<f32>() / n;
+    Some(variance.sqrt() / mean)
+}
+
+// calculate the longest turnaround divided by the shortest, 1 means every process waited the same
+// input: borrowed Vector of Processes, output: Option of f32, None if the shortest turnaround is 0 (a zero duration
+// process that ran as soon as it arrived), or there are no processes
+pub fn turnaround_ratio(processes: &Vec<Process>) -> Option<f32> {
+    let turnarounds = processes.iter().map(|p| p.completion - p.arrival);
+    let max = turnarounds.clone().fold(f32::MIN, f32::max);
+    let min = turnarounds.fold(f32::MAX, f32::min);
+    if processes.is_empty() || min == 0.0 {
+        return None;
+    }
+    Some(max / min)
+}
+
+// estimate the energy a schedule used with a simple power model: the CPU draws active_power for every unit of time
+// it is running a process and idle_power for every unit of time it sits idle. Busy time is the total duration of the
+// processes, idle time is whatever is left of the makespan. Finishing sooner means less idle time, so less energy
+// input: borrowed Vector of Processes, power while busy, power while idle, output: f32
+pub fn estimate_energy(processes: &Vec<Process>, active_power: f32, idle_power: f32) -> f32 {
+    let busy: f32 = processes.iter().map(|p| p.duration).sum();
+    // max(0) because on more than one CPU (gang) the busy time can add up to more than the makespan
+    let idle = (makespan(processes) - busy).max(0.0);
+    busy * active_power + idle * idle_power
+}
+
+// calculate a fingerprint of a schedule, a single number that is the same for two schedules exactly when (almost
+// certainly) every process has the same first_run and completion. Processes are hashed in pid order so the order of
+// the vector doesn't matter. This uses FNV-1a instead of the standard library hasher because the standard library
+// doesn't promise its hash stays the same between Rust versions, and a fingerprint should stay comparable
+// input: borrowed Vector of Processes, output: u64
+pub fn schedule_fingerprint(processes: &Vec<Process>) -> u64 {
+    let mut sorted: Vec<&Process> = processes.iter().collect();
+    sorted.sort_by_key(|p| p.pid);
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for p in sorted {
+        // to_bits gives the exact bits of the float, to_le_bytes splits a number into its bytes
+        let fields = [(p.pid as u64).to_le_bytes(), (p.first_run.to_bits() as u64).to_le_bytes(), (p.completion.to_bits() as u64).to_le_bytes()];
+        for byte in fields.iter().flatten() {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+// prints processes. With relative on, first_run and completion are printed as the time since the process arrived
+// (so they are its response and turnaround times) instead of the simulator's clock
+// input: borrowed Vector of Processes, relative, output: None
+pub fn show_processes(processes: &Vec<Process>, relative: bool) {
+    let p_iter = processes.iter();
+    // note here, this function, println!, the exlamation mark indicates this is a macro. This macro is by default included in the prelude
+    // of the program. This is because Rust does not support variable arguments, so println has to be implemented as a macro to use format parameters
+    if relative {
+        println!("Processes (first_run and completion relative to arrival):");
+    } else {
+        println!("Processes:");
+    }
+    for p in p_iter {
+        show_process(p, relative);
+    }
+}
+
+// prints the line show_processes prints for one process
+// input: borrowed Process, relative, output: None
+pub fn show_process(p: &Process, relative: bool) {
+    let initial_wait = p.first_run - p.arrival;
+    let ready_wait = p.ready_wait;
+    // only the printed copy is shifted, the schedule itself stays the same
+    let p = if relative {
+        Process { first_run: p.first_run - p.arrival, completion: p.completion - p.arrival, ..p.clone() }
+    } else {
+        p.clone()
+    };
+    // {} here uses the Display implementation for Process, see above. The initial wait is the response time, how long
+    // it waited before it first ran, the ready wait is all the waiting it did after that
+    println!("\t{}, initial_wait={}, ready_wait={}", p, initial_wait, ready_wait);
+}
+
+// calculate how long a process took from arriving to completing
+// input: borrowed Process, output: f32, the turnaround time
+pub fn turnaround_time(p: &Process) -> f32 {
+    p.completion - p.arrival
+}
+
+// the k processes with the longest turnaround, longest first (ties in pid order). Only the k are sorted,
+// select_nth_unstable_by puts the k longest at the front of the list in any order first, so a big workload isn't sorted
+// just to print the top of it
+// input: borrowed Vector of Processes, k, output: Vector of borrowed Processes
+pub fn worst_turnarounds(processes: &Vec<Process>, k: usize) -> Vec<&Process> {
+    let longest_first = |a: &&Process, b: &&Process| {
+        OrderedF32(turnaround_time(b)).cmp(&OrderedF32(turnaround_time(a))).then(a.pid.cmp(&b.pid))
+    };
+    let mut worst: Vec<&Process> = processes.iter().collect();
+    if k < worst.len() {
+        worst.select_nth_unstable_by(k, longest_first);
+        worst.truncate(k);
+    }
+    worst.sort_by(longest_first);
+    worst
+}
+
+// prints the lines show_processes prints, but only for the k processes with the longest turnaround, longest first
+// input: borrowed Vector of Processes, k, relative, output: None
+pub fn show_worst_processes(processes: &Vec<Process>, k: usize, relative: bool) {
+    println!("Processes (the {k} with the longest turnaround):");
+    for p in worst_turnarounds(processes, k) {
+        show_process(p, relative);
+    }
+}
+
+// calculate how long a process spent waiting in total, before it first ran and after
+// input: borrowed Process, output: f32, the waiting time
+pub fn waiting_time(p: &Process) -> f32 {
+    p.first_run - p.arrival + p.ready_wait
+}
+
+// prints every completed process that missed its deadline. A hard deadline miss is a critical failure, one is enough
+// to make the schedule wrong, so those are counted on their own. Soft misses are only late, for them it adds up the
+// tardiness, how far past the deadline each one completed
+// input: borrowed Vector of Processes, output: None
+pub fn show_deadlines(processes: &Vec<Process>) {
+    let mut hard_misses = 0;
+    let mut tardiness = 0.0;
+    println!("Deadline Misses:");
+    for p in processes {
+        let deadline = match p.deadline {
+            Some(deadline) if p.completion > deadline => deadline,
+            _ => continue,
+        };
+        if p.hard_deadline {
+            hard_misses += 1;
+            println!("\tCRITICAL pid={} missed its hard deadline {deadline}, completed at {}", p.pid, p.completion);
+        } else {
+            tardiness += p.completion - deadline;
+            println!("\tpid={} missed its soft deadline {deadline} by {}", p.pid, p.completion - deadline);
+        }
+    }
+    if hard_misses > 0 {
+        println!("Hard Deadline Misses:    {hard_misses} (CRITICAL, this schedule is not valid)");
+    } else {
+        println!("Hard Deadline Misses:    0");
+    }
+    println!("Soft Deadline Tardiness: {tardiness}");
+}
+
+// prints how many time slices rr-multi ran a process on a CPU other than its affinity, and which processes that
+// happened to. Nothing is printed if no process in the workload has an affinity
+// input: borrowed Vector of Processes, output: None
+pub fn show_affinity_violations(processes: &Vec<Process>) {
+    if processes.iter().all(|p| p.affinity.is_none()) {
+        return;
+    }
+    let total: usize = processes.iter().map(|p| p.affinity_violations).sum();
+    let pids: Vec<String> = processes.iter().filter(|p| p.affinity_violations > 0).map(|p| p.pid.to_string()).collect();
+    match pids.is_empty() {
+        true => println!("Affinity Violations:     0 time slices off the affinity CPU"),
+        false => println!("Affinity Violations:     {total} time slices off the affinity CPU (pids: {})", pids.join(" ")),
+    }
+}
+
+// prints how many processes rr completed early because they used up their cpu_budget, and which ones. Nothing is
+// printed if no process in the workload has a budget
+// input: borrowed Vector of Processes, output: None
+pub fn show_budgets(processes: &Vec<Process>) {
+    if processes.iter().all(|p| p.cpu_budget.is_none()) {
+        return;
+    }
+    let exhausted: Vec<String> = processes.iter().filter(|p| p.budget_exhausted).map(|p| p.pid.to_string()).collect();
+    println!("Budget Exhausted:        {} (pids: {})", exhausted.len(), exhausted.join(" "));
+}
+
+// splits the range of durations in the workload into at most HISTOGRAMBUCKETS buckets of the same width and counts
+// the processes in each. Durations are whole numbers, so a bucket is a range of whole numbers, and there are fewer
+// buckets when there are fewer different values than that
+// input: borrowed Vector of Processes, output: Vector of (smallest duration, largest duration, count), one per bucket
+pub fn duration_histogram(processes: &Vec<Process>) -> Vec<(f32, f32, usize)> {
+    if processes.is_empty() {
+        return Vec::new();
+    }
+    let min = processes.iter().fold(f32::MAX, |acc, p| acc.min(p.duration));
+    let max = processes.iter().fold(f32::MIN, |acc, p| acc.max(p.duration));
+    let width = ((max - min + 1.0) / HISTOGRAMBUCKETS).ceil();
+    let buckets = ((max - min + 1.0) / width).ceil() as usize;
+    let mut histogram: Vec<(f32, f32, usize)> = (0..buckets)
+        .map(|i| (min + i as f32 * width, min + (i + 1) as f32 * width - 1.0, 0))
+        .collect();
+    for p in processes {
+        histogram[((p.duration - min) / width) as usize].2 += 1;
+    }
+    histogram
+}
+
+// prints duration_histogram as one row of # per bucket, like "  3-4 | ### 3"
+// input: borrowed Vector of Processes, output: None
+pub fn show_duration_histogram(processes: &Vec<Process>) {
+    let histogram = duration_histogram(processes);
+    let label = |lo: f32, hi: f32| if lo == hi { lo.to_string() } else { format!("{lo}-{hi}") };
+    let width = histogram.iter().map(|b| label(b.0, b.1).len()).max().unwrap_or(0);
+    println!("Duration Histogram:");
+    for (lo, hi, count) in histogram {
+        let bar = format!("{} {count}", "#".repeat(count));
+        println!("\t{:>width$} | {}", label(lo, hi), bar.trim_start());
+    }
+}
+
+// groups the completed processes by arrival time into buckets of the same width starting at 0, [0, width),
+// [width, 2 * width) and so on, and averages the turnaround in each. Buckets nothing arrived in are left out
+// input: borrowed Vector of Processes, bucket width, output: Vector of (bucket start, count, average turnaround) in order
+pub fn arrival_buckets(processes: &Vec<Process>, width: f32) -> Vec<(f32, usize, f32)> {
+    let mut buckets: Vec<(f32, usize, f32)> = Vec::new();
+    for p in processes {
+        let start = (p.arrival / width).floor() * width;
+        match buckets.iter_mut().find(|b| b.0 == start) {
+            Some(bucket) => {
+                bucket.1 += 1;
+                bucket.2 += p.completion - p.arrival;
+            }
+            None => buckets.push((start, 1, p.completion - p.arrival)),
+        }
+    }
+    buckets.sort_by_key(|b| OrderedF32(b.0));
+    // until here the last field is the total turnaround of the bucket
+    for bucket in buckets.iter_mut() {
+        bucket.2 /= bucket.1 as f32;
+    }
+    buckets
+}
+
+// prints arrival_buckets as one line per bucket, like "[10, 20): 3 processes, average turnaround 12.5"
+// input: borrowed Vector of Processes, bucket width, output: None
+pub fn show_arrival_buckets(processes: &Vec<Process>, width: f32) {
+    println!("Turnaround by Arrival:");
+    for (start, count, turnaround) in arrival_buckets(processes, width) {
+        println!("	[{start}, {}): {count} processes, average turnaround {turnaround}", start + width);
+    }
+}
+
+// groups the completed processes by completion time into windows width wide that slide by half a width, centered on 0,
+// width / 2, width and so on, and averages the turnaround in each. Every time is in exactly two windows, so every
+// process counts twice and the windows averaged by their counts give back the overall average. Nothing completes before
+// 0, so the first window starts there, and windows nothing completed in are left out
+// input: borrowed Vector of Processes, window width, output: Vector of (window start, window end, count, average
+// turnaround) in order
+pub fn window_turnarounds(processes: &Vec<Process>, width: f32) -> Vec<(f32, f32, usize, f32)> {
+    let mut windows: Vec<(f32, f32, usize, f32)> = Vec::new();
+    let last = processes.iter().map(|p| p.completion).fold(0.0, f32::max);
+    let mut center: f32 = 0.0;
+    while center - width / 2.0 <= last {
+        let (start, end) = (center - width / 2.0, center + width / 2.0);
+        let inside: Vec<&Process> = processes.iter().filter(|p| p.completion >= start && p.completion < end).collect();
+        if !inside.is_empty() {
+            let total: f32 = inside.iter().map(|p| p.completion - p.arrival).sum();
+            windows.push((start.max(0.0), end, inside.len(), total / inside.len() as f32));
+        }
+        center += width / 2.0;
+    }
+    windows
+}
+
+// prints window_turnarounds as one line per window, like "[10, 20): 3 processes, average turnaround 12.5"
+// input: borrowed Vector of Processes, window width, output: None
+pub fn show_window_turnarounds(processes: &Vec<Process>, width: f32) {
+    println!("Turnaround by Completion Window:");
+    for (start, end, count, turnaround) in window_turnarounds(processes, width) {
+        println!("\t[{start}, {end}): {count} processes, average turnaround {turnaround}");
+    }
+}
+
+// prints every process that waited longer than threshold in total, the ones a scheduler without aging (like sjf or
+// priority) kept pushing to the back
+// input: borrowed Vector of Processes, threshold, output: None
+pub fn show_starvation(processes: &Vec<Process>, threshold: f32) {
+    let starved: Vec<&Process> = processes.iter().filter(|p| waiting_time(p) > threshold).collect();
+    if starved.is_empty() {
+        println!("No process waited longer than {threshold}");
+        return;
+    }
+    println!("Starved Processes (waited longer than {threshold}):");
+    for p in starved {
+        println!("\tpid={}, waited={}", p.pid, waiting_time(p));
+    }
+}
+
+// calculate when a process was on the CPU for the first and the last time, from the run segments
+// input: borrowed slice of run segments, pid, output: Option, (first run start, last run start, last run end) or None
+// if the process never ran
+pub fn run_span(segments: &[(f32, f32, usize)], pid: usize) -> Option<(f32, f32, f32)> {
+    let mut runs = segments.iter().filter(|s| s.2 == pid);
+    let first = runs.next()?;
+    let last = runs.next_back().unwrap_or(first);
+    Some((first.0, last.0, last.1))
+}
+
+// prints every process's span, the time from it first starting to run until it last stopped. A process that runs to
+// completion in one go has a span of exactly its duration, anything more is time it was on and off the CPU (so its
+// cache went cold in between), which is where rr pays for its response times
+// input: borrowed Vector of Processes, borrowed slice of run segments, output: None
+pub fn show_spans(processes: &Vec<Process>, segments: &[(f32, f32, usize)]) {
+    let mut total = 0.0;
+    println!("Run Spans:");
+    for p in processes {
+        let (first, last, end) = match run_span(segments, p.pid) {
+            Some(span) => span,
+            None => continue,
+        };
+        total += end - first;
+        println!("	pid={}, first_run={first}, last_run={last}, span={}", p.pid, end - first);
+    }
+    println!("Average Span:            {}", total / processes.len() as f32);
+}
+
+// prints processes and metrics, relative is passed on to show_processes. With top_n only that many processes are
+// printed (see show_worst_processes), the metrics are still over all of them
+// input: borrowed Vector of Processes, relative, top_n, output: None
+pub fn show_metrics(processes : &Vec<Process>, relative: bool, top_n: Option<usize>){
+    let turn = avg_turnaround(processes);
+    let resp = avg_response(processes);
+    match top_n {
+        Some(k) => show_worst_processes(processes, k, relative),
+        None => show_processes(processes, relative),
+    }
+    println!("Average Turnaround Time: {}", turn);
+    println!("Average Response Time:   {}", resp);
+    println!("Makespan:                {}", makespan(processes));
+}
+
+// prints the run as one line of key=value pairs, like "algo=fifo workload=jobs.txt n=100 avg_tt=45.30 avg_rt=12.10",
+// so the results of lots of runs can be logged to one file and pulled apart with grep and awk
+// input: algorithm name, workload name, borrowed Vector of Processes, output: None
+pub fn show_summary_line(algo: &str, workload: &str, processes: &Vec<Process>) {
+    println!("algo={algo} workload={workload} n={} avg_tt={:.2} avg_rt={:.2}",
+        processes.len(), avg_turnaround(processes), avg_response(processes));
+}
+
+// calculate total priority inversion time, how long a process ran while some higher priority process was ready and waiting
+// a higher priority process counts as waiting from its arrival until its completion, except while it is the one running
+// An interrupted run has processes that never completed (some of them ran before it stopped), they count as waiting
+// from their arrival on
+// input: borrowed Vector of run segments, borrowed Vector of completed Processes, borrowed Vector of incomplete
+// Processes, output: f32
+pub fn priority_inversion(segments: &Vec<(f32, f32, usize)>, processes: &Vec<Process>, incomplete: &Vec<Process>) -> f32 {
+    // (priority, arrival, completion) of every process, infinity for the ones that never completed
+    let every: Vec<(i32, f32, f32)> = processes.iter().map(|p| (p.priority, p.arrival, p.completion))
+        .chain(incomplete.iter().map(|p| (p.priority, p.arrival, f32::INFINITY)))
+        .collect();
+    let mut total = 0.0;
+    for &(start, end, pid) in segments {
+        let running = match processes.iter().chain(incomplete).find(|p| p.pid == pid) {
+            Some(p) => p,
+            None => continue,
+        };
+        // the parts of this segment each higher priority process spent waiting, sorted so overlaps can be merged
+        let mut waiting: Vec<(f32, f32)> = every.iter()
+            .filter(|q| q.0 < running.priority)
+            .map(|q| (q.1.max(start), q.2.min(end)))
+            .filter(|(from, to)| from < to)
+            .collect();
+        waiting.sort_by_key(|w| OrderedF32(w.0));
+
+        // add up the length of the union of those intervals, so two processes waiting at once is not counted twice
+        let mut covered_until = start;
+        for (from, to) in waiting {
+            let from = from.max(covered_until);
+            if to > from {
+                total += to - from;
+                covered_until = to;
+            }
+        }
+    }
+    total
+}
+
+// checks that no process first ran before it arrived, the one thing every schedule has to get right whatever the
+// settings. It only needs the scheduled processes, so it can run after every algorithm
+// input: borrowed Vector of Processes, output: Result, Err names the first process (in the order given) that started early
+pub fn check_no_early_start(processes: &Vec<Process>) -> Result<(), String> {
+    // floats are not exact, allow a little slack
+    let epsilon = 1e-3;
+    match processes.iter().find(|p| p.first_run + epsilon < p.arrival) {
+        Some(p) => Err(format!("pid {} first ran at {} before arriving at {}", p.pid, p.first_run, p.arrival)),
+        None => Ok(()),
+    }
+}
+
+// checks the invariants every correct schedule of the workload has to satisfy: every process completed, no process
+// started before it arrived (check_no_early_start) or completed sooner than arrival + duration, and the CPU was not busy
+// for longer than the makespan (it can't run two things at once). This assumes the default SimConfig, a faster CPU finishes sooner
+// input: borrowed Vector of Processes (the workload), borrowed ScheduleResult, output: Result, Err describes the first problem
+pub fn validate_schedule(workload: &Vec<Process>, result: &ScheduleResult) -> Result<(), String> {
+    // floats are not exact, allow a little slack
+    let epsilon = 1e-3;
+    if result.processes.len() != workload.len() || !result.incomplete.is_empty() {
+        return Err(format!("{} of {} processes completed", result.processes.len(), workload.len()));
+    }
+    check_no_early_start(&result.processes)?;
+    for w in workload {
+        // the ? returns the Err right away if there is no process with this pid, ok_or turns the None of find into an Err
+        let p = result.processes.iter().find(|p| p.pid == w.pid).ok_or(format!("pid {} is missing", w.pid))?;
+        if p.completion + epsilon < w.arrival + w.duration {
+            return Err(format!("pid {} completed at {}, sooner than arrival {} + duration {}", w.pid, p.completion, w.arrival, w.duration));
+        }
+    }
+    let busy: f32 = result.segments.iter().map(|s| s.1 - s.0).sum();
+    if busy > makespan(&result.processes) + epsilon {
+        return Err(format!("the CPU was busy for {busy}, longer than the makespan {}", makespan(&result.processes)));
+    }
+    Ok(())
+}
+
+// checks that the CPU time in the run segments adds up to the work the processes needed, nothing lost or run twice.
+// Every completed process needed its whole duration (or only what it ran before using up its cpu_budget), and an
+// incomplete one what it got done before the simulation stopped. speed and tick are what the algorithm simulated, a
+// tick based algorithm runs every process for a whole number of steps of tick doing speed * tick work each, and the
+// last one overshoots when that doesn't divide the process's work, so each one's work is rounded up to whole steps.
+// tick is 0 for the algorithms that run every process for exactly as long as its work takes at speed (stcf, rr and mlfq
+// with partial_ticks or preempt_on_arrival, and the ones that ignore the speed with a speed of 1)
+// input: borrowed ScheduleResult, speed, tick, output: Result, Err says how far the busy time was off
+pub fn check_conservation(result: &ScheduleResult, speed: f32, tick: f32) -> Result<(), String> {
+    let epsilon = 1e-3;
+    let cpu_time = |work: f32| if tick == 0.0 { work / speed } else { (work / (speed * tick) - epsilon).ceil() * tick };
+    let completed = result.processes.iter()
+        .map(|p| if p.budget_exhausted { p.duration - p.remaining_time } else { p.duration });
+    let unfinished = result.incomplete.iter().map(|p| p.duration - p.remaining_time);
+    let needed: f32 = completed.chain(unfinished).map(cpu_time).sum();
+    let busy: f32 = result.segments.iter().map(|s| s.1 - s.0).sum();
+    if (busy - needed).abs() > epsilon {
+        return Err(format!("the CPU was busy for {busy}, but the processes needed {needed}"));
+    }
+    Ok(())
+}
+
+// checks that no schedule of the workload can run past MAXEXACTTIME. The latest it could end is the last arrival plus
+// every step of every process back to back, each with a dispatch in front of it, and a slower speed makes that longer
+// (fifo and the others that ignore the speed only ever take less). Storing times as f64 inside the algorithms wouldn't
+// help, every time ends up in a Process as an f32, so a workload like that is refused instead of getting wrong answers
+// (or a tick loop that never ends)
+// input: borrowed Vector of Processes, borrowed SimConfig, output: Result, Err says how far the schedule could go
+pub fn check_time_range(workload: &Vec<Process>, config: &SimConfig) -> Result<(), String> {
+    let last_arrival = workload.iter().fold(0.0, |acc: f64, p| acc.max(p.arrival as f64));
+    let step = (config.speed.min(1.0) * config.tick) as f64;
+    let steps: f64 = workload.iter().map(|p| (p.duration as f64 / step).ceil()).sum();
+    let end = last_arrival + steps * (config.tick + config.dispatch_overhead) as f64;
+    if end > MAXEXACTTIME as f64 {
+        return Err(format!("the schedule could run until t={end}, times past {MAXEXACTTIME} can't be simulated exactly"));
+    }
+    Ok(())
+}
+
+// prints the processes a simulation did not finish (because it was interrupted, or a gang could never fit), nothing if everything finished
+// input: borrowed Vector of Processes, output: None
+pub fn show_incomplete(incomplete: &Vec<Process>) {
+    if incomplete.is_empty() {
+        return;
+    }
+    println!("{} processes incomplete (not counted in the averages above):", incomplete.len());
+    for p in incomplete {
+        println!("\tpid={}, arrival={}, duration={}, remaining_time={}", p.pid, p.arrival, p.duration, p.remaining_time);
+    }
+}
+
+// finds the process that was on the CPU at time t. A segment covers its start but not its end, so at a time where one
+// process stops and the next starts the answer is the one starting
+// input: run segments, a time, output: Option, the pid, or None if the CPU was idle at t
+pub fn process_at_time(segments: &[(f32, f32, usize)], t: f32) -> Option<usize> {
+    segments.iter().find(|s| s.0 <= t && t < s.1).map(|s| s.2)
+}
+
+// prints the pid on every CPU for every tick from the first segment to the end of the last one, like
+// "t=2: cpu0=0 cpu1=idle", the multi CPU version of --at. The CPUs are the ones the algorithm ran each segment on
+// input: borrowed slice of run segments, borrowed slice of the CPU of each segment, number of CPUs, output: None
+pub fn show_core_trace(segments: &[(f32, f32, usize)], segment_cpus: &[usize], cpus: usize) {
+    println!("Core Trace:");
+    if segments.is_empty() {
+        return;
+    }
+    let start = segments.iter().fold(f32::MAX, |acc, s| acc.min(s.0));
+    let end = segments.iter().fold(f32::MIN, |acc, s| acc.max(s.1));
+    let mut t = start;
+    while t < end {
+        let cores: Vec<String> = (0..cpus).map(|cpu| {
+            // like process_at_time, a segment covers its start but not its end
+            let running = segments.iter().zip(segment_cpus).find(|(s, &on)| on == cpu && s.0 <= t && t < s.1);
+            match running {
+                Some((s, _)) => format!("cpu{cpu}={}", s.2),
+                None => format!("cpu{cpu}=idle"),
+            }
+        }).collect();
+        println!("\tt={t}: {}", cores.join(" "));
+        t += 1.0;
+    }
+}
+
+// counts how many times each process was preempted, taken off the CPU before it was done. add_segment merges back to
+// back segments, so every segment but a process's last one ended with something else getting the CPU
+// input: borrowed slice of run segments, output: HashMap from pid to its number of preemptions
+pub fn preemptions_per_process(segments: &[(f32, f32, usize)]) -> HashMap<usize, usize> {
+    let mut runs: HashMap<usize, usize> = HashMap::new();
+    for &(_, _, pid) in segments {
+        *runs.entry(pid).or_insert(0) += 1;
+    }
+    runs.into_iter().map(|(pid, count)| (pid, count - 1)).collect()
+}
+
+// counts the context switches in a schedule, every time the CPU goes from running one process to running a different
+// one, with or without idle time in between. The first dispatch isn't a switch, and neither is a process getting the
+// CPU back after the CPU was idle
+// input: borrowed slice of run segments, output: number of context switches
+pub fn context_switches(segments: &[(f32, f32, usize)]) -> usize {
+    segments.windows(2).filter(|w| w[0].2 != w[1].2).count()
+}
+
+// prints preemptions_per_process in pid order, and the process that was preempted the most (the lowest pid if
+// several were preempted as often)
+// input: borrowed slice of run segments, output: None
+pub fn show_preemptions(segments: &[(f32, f32, usize)]) {
+    let mut preemptions: Vec<(usize, usize)> = preemptions_per_process(segments).into_iter().collect();
+    preemptions.sort();
+    println!("Preemptions:");
+    for (pid, count) in &preemptions {
+        println!("\tpid={pid}, preemptions={count}");
+    }
+    // max_by_key keeps the last of equal maximums, going through the pids backwards makes that the lowest pid
+    if let Some((pid, count)) = preemptions.iter().rev().max_by_key(|(_, count)| *count) {
+        println!("Most Preemptions:        {count} (pid {pid})");
+    }
+}
+
+// returns a copy of the workload with delta added to the duration of one process, for --sensitivity. Its last burst
+// takes the change so the bursts still add up to the duration
+// input: borrowed Vector of Processes, pid, delta, output: Option, the new workload, None if there is no process with
+// that pid or the change would make its last burst negative
+pub fn perturb_duration(workload: &Vec<Process>, pid: usize, delta: f32) -> Option<Vec<Process>> {
+    let mut perturbed = workload.clone();
+    let p = perturbed.iter_mut().find(|p| p.pid == pid)?;
+    let last = p.bursts.last_mut()?;
+    if *last + delta < 0.0 {
+        return None;
+    }
+    *last += delta;
+    p.duration += delta;
+    p.remaining_time = p.duration;
+    Some(perturbed)
+}
+
+// prints the metrics of the schedule before and after one process got delta longer (see perturb_duration), the
+// average turnaround of every other process, and how much the average turnaround changed per unit of delta. If the
+// others' turnaround didn't move, only the process itself felt the change
+// input: borrowed Vectors of the Processes before and after, pid, delta, output: None
+pub fn show_sensitivity(before: &Vec<Process>, after: &Vec<Process>, pid: usize, delta: f32) {
+    let others = |processes: &Vec<Process>| -> Vec<Process> { processes.iter().filter(|p| p.pid != pid).cloned().collect() };
+    println!("Sensitivity (pid {pid}, duration {delta:+}):");
+    println!("\tAverage Turnaround Time: {} -> {}", avg_turnaround(before), avg_turnaround(after));
+    println!("\tAverage Response Time:   {} -> {}", avg_response(before), avg_response(after));
+    println!("\tMakespan:                {} -> {}", makespan(before), makespan(after));
+    if before.len() > 1 {
+        println!("\tOthers' Turnaround:      {} -> {}", avg_turnaround(&others(before)), avg_turnaround(&others(after)));
+    }
+    println!("Turnaround per Duration: {}", (avg_turnaround(after) - avg_turnaround(before)) / delta);
+}
+
+// prints the pid of every run segment in order, so the order processes were put on the CPU (including repeats)
+// input: borrowed Vector of run segments, output: None
+pub fn show_dispatch_order(segments: &Vec<(f32, f32, usize)>) {
+    let pids: Vec<String> = segments.iter().map(|s| s.2.to_string()).collect();
+    println!("Dispatch Order: {}", pids.join(" "));
+}
+
+// prints an ASCII Gantt chart, one row per process and one column per scale units of time from the first segment to
+// the last, with # where the process was on the CPU, like "pid 0 |##..#|". Under the rows is a time axis with a + and
+// a label every few columns
+// input: borrowed Vector of GanttSegments, units of time per column, output: None
+pub fn show_gantt(gantt: &Vec<GanttSegment>, scale: f32) {
+    if gantt.is_empty() {
+        return;
+    }
+    let first = gantt.iter().fold(f32::MAX, |acc, g| acc.min(g.start));
+    let last = gantt.iter().fold(f32::MIN, |acc, g| acc.max(g.end));
+    let mut pids: Vec<usize> = gantt.iter().map(|g| g.pid).collect();
+    pids.sort();
+    pids.dedup();
+    // the pids are padded to the same width so every row's bar (and the axis) starts in the same column
+    let width = pids.last().unwrap().to_string().len();
+    let columns = ((last - first) / scale).ceil() as usize;
+    println!("Gantt Chart (from t={first} to t={last}):");
+    for pid in pids {
+        let row: String = gantt_row(gantt, pid, first, columns, scale).into_iter().collect();
+        println!("\tpid {pid:>width$} |{row}|");
+    }
+    let (ticks, labels) = gantt_axis(first, last, columns, scale);
+    let pad = " ".repeat("pid ".len() + width + 1);
+    println!("\t{pad}{ticks}");
+    println!("\t{pad}{labels}");
+}
+
+// the columns of one process's row of a Gantt chart. A column is filled if the process was running at the middle of
+// that column's time. A process that only ran for part of a column (easy to happen once a column is many units of
+// time) gets a : instead, so short segments don't disappear from the chart
+// input: borrowed slice of GanttSegments, pid, first time of the chart, number of columns, units of time per column,
+// output: Vector of #, : or . per column
+fn gantt_row(gantt: &[GanttSegment], pid: usize, first: f32, columns: usize, scale: f32) -> Vec<char> {
+    (0..columns)
+        .map(|i| (first + i as f32 * scale, first + (i + 1) as f32 * scale))
+        .map(|(start, end)| {
+            let mid = (start + end) / 2.0;
+            let ran = gantt.iter().filter(|g| g.pid == pid);
+            if ran.clone().any(|g| g.start <= mid && mid < g.end) {
+                '#'
+            } else if ran.clone().any(|g| g.start < end && start < g.end) {
+                ':'
+            } else {
+                '.'
+            }
+        })
+        .collect()
+}
+
+// prints the Gantt charts of two schedules of the same workload stacked on one time axis, to compare them side by
+// side. Both cover the time from the earliest start to the latest end of either, so a column is the same time in both,
+// and every process has its own color (see PIDCOLORS) in its label and bar, the same one in both charts
+// input: borrowed slices of GanttSegments of the two schedules, their names, units of time per column, output: None
+pub fn render_comparison_gantt(a: &[GanttSegment], b: &[GanttSegment], names: [&str; 2], scale: f32) {
+    if a.is_empty() && b.is_empty() {
+        return;
+    }
+    let first = a.iter().chain(b).fold(f32::MAX, |acc, g| acc.min(g.start));
+    let last = a.iter().chain(b).fold(f32::MIN, |acc, g| acc.max(g.end));
+    let mut pids: Vec<usize> = a.iter().chain(b).map(|g| g.pid).collect();
+    pids.sort();
+    pids.dedup();
+    let width = pids.last().unwrap().to_string().len();
+    let columns = ((last - first) / scale).ceil() as usize;
+    println!("Gantt Chart Comparison (from t={first} to t={last}):");
+    for (name, gantt) in names.iter().zip([a, b]) {
+        println!("{name}");
+        for &pid in &pids {
+            let color = PIDCOLORS[pid % PIDCOLORS.len()];
+            let row: String = gantt_row(gantt, pid, first, columns, scale).into_iter()
+                .map(|c| if c == '.' { c.to_string() } else { format!("{color}{c}{RESETCOLOR}") })
+                .collect();
+            println!("\t{color}pid {pid:>width$}{RESETCOLOR} |{row}|");
+        }
+    }
+    let (ticks, labels) = gantt_axis(first, last, columns, scale);
+    let pad = " ".repeat("pid ".len() + width + 1);
+    println!("\t{pad}{ticks}");
+    println!("\t{pad}{labels}");
+}
+
+// builds the two lines of the Gantt chart's time axis. Position k of the axis is the left edge of column k, so the
+// time first + k * scale, and the last position lines up with the closing | of the rows. The ticks are GANTTTICK
+// columns apart, rounded up to the next 1, 2 or 5 times a power of 10 so the labels are round numbers. When a tick
+// doesn't sit exactly on a column edge (first isn't a multiple of it, or the scale doesn't divide it) it goes on the
+// nearest one
+// input: first and last time of the chart, number of columns, units of time per column, output: (tick line like
+// "+----+--", label line)
+fn gantt_axis(first: f32, last: f32, columns: usize, scale: f32) -> (String, String) {
+    let wanted = GANTTTICK * scale;
+    let power = 10f32.powf(wanted.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0].iter().map(|m| m * power).find(|&step| step >= wanted * 0.999).unwrap();
+    let mut ticks: Vec<char> = vec!['-'; columns + 1];
+    let mut labels: Vec<char> = vec![' '; columns + 1];
+    let mut t = (first / step).ceil() * step;
+    while t <= last {
+        let k = ((t - first) / scale).round() as usize;
+        if k <= columns {
+            ticks[k] = '+';
+            let label: Vec<char> = t.to_string().chars().collect();
+            // a label is left out if it would run into the one before it
+            if k + label.len() > labels.len() {
+                labels.resize(k + label.len(), ' ');
+            }
+            if (k.saturating_sub(1)..k + label.len()).all(|i| labels[i] == ' ') {
+                labels[k..k + label.len()].copy_from_slice(&label);
+            }
+        }
+        t += step;
+    }
+    (ticks.into_iter().collect(), labels.into_iter().collect::<String>().trim_end().to_string())
+}
+
+// prints the Gantt chart bars as a JSON array, for drawing the chart with something else
+// input: borrowed Vector of GanttSegments, output: None
+pub fn show_gantt_json(gantt: &Vec<GanttSegment>) {
+    let bars: Vec<String> = gantt.iter().map(GanttSegment::to_json).collect();
+    println!("[{}]", bars.join(", "));
+}
+
+// reads the Gantt chart bars back from the JSON show_gantt_json prints, [{"pid": 0, "start": 0, "end": 2}, ...]. Text
+// that isn't an array of objects, a bar that is missing a field, or something other than a number in one is an Err
+// input: the JSON text, output: Result, the Vector of GanttSegments or what is wrong with a bar
+pub fn parse_gantt_json(text: &str) -> Result<Vec<GanttSegment>, String> {
+    let mut parser = JsonParser { chars: text.chars().collect(), pos: 0 };
+    let mut gantt = Vec::new();
+    // the parser is shared with the JSON workloads, so its errors say "JSON workload", this is only a chart
+    let syntax = |e: WorkloadError| match e {
+        WorkloadError::BadJson { position, reason } => format!("character {position}: {reason}"),
+        other => other.to_string(),
+    };
+    parser.expect('[').map_err(syntax)?;
+    while parser.peek() != Some(']') {
+        let fields = parser.object().map_err(syntax)?;
+        let field = |name: &str| -> Result<f32, String> {
+            let (_, value) = fields.iter().find(|(key, _)| key == name).ok_or(format!("bar {} has no {name}", gantt.len()))?;
+            value.parse().map_err(|_| format!("bar {}: {value} is not a valid {name}", gantt.len()))
+        };
+        let pid = field("pid")?;
+        if pid < 0.0 || pid.fract() != 0.0 {
+            return Err(format!("bar {}: {pid} is not a valid pid", gantt.len()));
+        }
+        gantt.push(GanttSegment { pid: pid as usize, start: field("start")?, end: field("end")? });
+        if parser.peek() == Some(',') {
+            parser.expect(',').map_err(syntax)?;
+        }
+    }
+    parser.expect(']').map_err(syntax)?;
+    Ok(gantt)
+}
+
+// works out every process's first_run, completion and ready_wait from the Gantt chart bars alone, the way the
+// algorithms do: first_run is the start of its first bar, completion the end of its last, and ready_wait the gaps
+// between its bars. On one CPU that is everything the metrics need, so a chart drawn by something else can be checked
+// against the run it came from. Processes without a bar (a duration of 0, or a run that was stopped) are left out
+// input: borrowed Vector of GanttSegments, borrowed Vector of Processes (the workload), output: Vector of Processes in
+// the order they completed
+pub fn metrics_from_segments(segments: &Vec<GanttSegment>, workload: &Vec<Process>) -> Vec<Process> {
+    let mut processes: Vec<Process> = Vec::new();
+    for p in workload {
+        let mut bars = segments.iter().filter(|s| s.pid == p.pid);
+        let first = match bars.next() {
+            Some(first) => first,
+            None => continue,
+        };
+        let mut p = Process { first_run: first.start, completion: first.end, remaining_time: 0.0, ..p.reset() };
+        for bar in bars {
+            p.ready_wait += bar.start - p.completion;
+            p.completion = bar.end;
+        }
+        processes.push(p);
+    }
+    processes.sort_by(|a, b| OrderedF32(a.completion).cmp(&OrderedF32(b.completion)).then(a.pid.cmp(&b.pid)));
+    processes
+}
+
+// prints whether metrics_from_segments on the chart gives back the first_run and completion the run reported for
+// every process, and every process where it doesn't
+// input: borrowed Vector of replayed Processes, borrowed Vector of the run's Processes, output: None
+pub fn show_gantt_replay(replayed: &Vec<Process>, processes: &Vec<Process>) {
+    let mut mismatches = Vec::new();
+    for p in processes {
+        match replayed.iter().find(|r| r.pid == p.pid) {
+            Some(r) if r.first_run == p.first_run && r.completion == p.completion => {}
+            Some(r) => mismatches.push(format!("pid {}: the chart has first_run={}, completion={}, the run has first_run={}, completion={}",
+                p.pid, r.first_run, r.completion, p.first_run, p.completion)),
+            None => mismatches.push(format!("pid {}: not in the chart", p.pid)),
+        }
+    }
+    for r in replayed.iter().filter(|r| processes.iter().all(|p| p.pid != r.pid)) {
+        mismatches.push(format!("pid {}: in the chart but didn't complete in the run", r.pid));
+    }
+    if mismatches.is_empty() {
+        println!("Gantt Replay: matches, every first_run and completion is the same");
+        return;
+    }
+    println!("Gantt Replay: {} processes differ", mismatches.len());
+    for mismatch in mismatches {
+        println!("\t{mismatch}");
+    }
+}
+
+// writes the run history as a VCD (value change dump) file for waveform viewers like GTKWave. Every pid that ran is
+// a 1 bit signal, named like pid0, that is 1 while the process is on the CPU and 0 otherwise. All of them start at 0,
+// and after that the file only has the times where one of them changes, each one once even if it stops and starts again
+// at the same time (which the viewer couldn't show anyway)
+// input: borrowed slice of run segments, path, output: Result, Err if the file couldn't be written
+pub fn export_vcd(segments: &[(f32, f32, usize)], path: &str) -> io::Result<()> {
+    let mut pids: Vec<usize> = segments.iter().map(|s| s.2).collect::<HashSet<usize>>().into_iter().collect();
+    pids.sort();
+    // every identifier is a short string of the printable characters from ! to ~, like a number in base 94
+    let identifier = |mut n: usize| {
+        let mut id = String::new();
+        loop {
+            id.push((b'!' + (n % 94) as u8) as char);
+            n /= 94;
+            if n == 0 {
+                return id;
+            }
+        }
+    };
+    let ids: HashMap<usize, String> = pids.iter().enumerate().map(|(i, &pid)| (pid, identifier(i))).collect();
+
+    // the value every signal has at the end of each time, ends go in first so a start at the same time wins
+    let mut changes: BTreeMap<u64, BTreeMap<usize, bool>> = BTreeMap::new();
+    for &(_, end, pid) in segments {
+        changes.entry((end * VCDSCALE).round() as u64).or_default().insert(pid, false);
+    }
+    for &(start, _, pid) in segments {
+        changes.entry((start * VCDSCALE).round() as u64).or_default().insert(pid, true);
+    }
+
+    let mut lines = vec![
+        "$version final_scheduler $end".to_string(),
+        "$timescale 1ms $end".to_string(),
+        "$scope module schedule $end".to_string(),
+    ];
+    for pid in &pids {
+        lines.push(format!("$var wire 1 {} pid{pid} $end", ids[pid]));
+    }
+    lines.push("$upscope $end".to_string());
+    lines.push("$enddefinitions $end".to_string());
+    lines.push("$dumpvars".to_string());
+    for pid in &pids {
+        lines.push(format!("0{}", ids[pid]));
+    }
+    lines.push("$end".to_string());
+    let mut running: HashSet<usize> = HashSet::new();
+    for (time, values) in changes {
+        // a process that stops and starts again at the same time didn't change
+        let changed: Vec<(usize, bool)> = values.into_iter().filter(|&(pid, on)| running.contains(&pid) != on).collect();
+        if changed.is_empty() {
+            continue;
+        }
+        lines.push(format!("#{time}"));
+        for (pid, on) in changed {
+            if on {
+                running.insert(pid);
+            } else {
+                running.remove(&pid);
+            }
+            lines.push(format!("{}{}", if on { 1 } else { 0 }, ids[&pid]));
+        }
+    }
+    std::fs::write(path, lines.join("\n") + "\n")
+}
+
+// prints the time average and the peak of the ready set length. The average is over the time from the first tick to
+// the end of the last one, ticks the CPU skipped because nothing was ready count as length 0
+// input: borrowed Vector of (time, queue length) tuples, output: None
+pub fn show_queue_stats(queue_lengths: &Vec<(f32, usize)>) {
+    if queue_lengths.is_empty() {
+        println!("Queue Length: not tracked for this algorithm");
+        return;
+    }
+    let span = queue_lengths.last().unwrap().0 + 1.0 - queue_lengths[0].0;
+    let total: usize = queue_lengths.iter().map(|q| q.1).sum();
+    let peak = queue_lengths.iter().map(|q| q.1).max().unwrap();
+    println!("Average Queue Length:    {}", total as f32 / span);
+    println!("Peak Queue Length:       {}", peak);
+}
+
+// prints what is wanted about a process right after it completes, stcf, rr and mlfq call this with the one that just
+// did. With config.stream_output the process itself, so a long simulation shows results as it goes instead of all at
+// the end (Rust's stdout is line buffered, so each line comes out right away even into a pipe), and with
+// config.running_average the average turnaround of everything that has completed so far, to watch it settle. Does
+// nothing unless one of them is on
+// input: borrowed SimConfig, borrowed Vector of completed Processes, output: None
+fn show_completion(config: &SimConfig, complete: &Vec<Process>) {
+    let p = complete.last().unwrap();
+    if config.stream_output {
+        println!("Completed: {p}");
+    }
+    if config.running_average {
+        println!("t={} pid={} completed, running average turnaround: {}", p.completion, p.pid, avg_turnaround(complete));
+    }
+}
+
+// writes every completed process to a CSV file in pid order, one row each with its pid, arrival, duration, first_run and
+// completion. With annotate it also has the metrics worked out from those, turnaround, response, waiting (turnaround -
+// duration, the time it wasn't running) and normalized_turnaround (turnaround / duration), and a last row with AVG in
+// the pid column and the average of each of them, so the file can be analyzed without working them out again
+// input: path, borrowed Vector of Processes, whether to add the metrics, output: Result, Err if the file couldn't be written
+pub fn write_csv(path: &str, processes: &Vec<Process>, annotate: bool) -> io::Result<()> {
+    let metrics = |p: &Process| {
+        let turnaround = p.completion - p.arrival;
+        [turnaround, p.first_run - p.arrival, turnaround - p.duration, turnaround / p.duration]
+    };
+    let mut in_order: Vec<&Process> = processes.iter().collect();
+    in_order.sort_by_key(|p| p.pid);
+    let mut header = "pid,arrival,duration,first_run,completion".to_string();
+    if annotate {
+        header += ",turnaround,response,waiting,normalized_turnaround";
+    }
+    let mut lines = vec![header];
+    for p in &in_order {
+        let mut line = format!("{},{},{},{},{}", p.pid, p.arrival, p.duration, p.first_run, p.completion);
+        if annotate {
+            for value in metrics(p) {
+                line += &format!(",{value}");
+            }
+        }
+        lines.push(line);
+    }
+    if annotate && !in_order.is_empty() {
+        let n = in_order.len() as f32;
+        let mut sums = [0.0; 4];
+        for p in &in_order {
+            for (sum, value) in sums.iter_mut().zip(metrics(p)) {
+                *sum += value;
+            }
+        }
+        let averages: Vec<String> = sums.iter().map(|sum| (sum / n).to_string()).collect();
+        // the columns the averages don't apply to are left empty
+        lines.push(format!("AVG,,,,,{}", averages.join(",")));
+    }
+    std::fs::write(path, lines.join("\n") + "\n")
+}
+
+// appends every completed process to the results table of a SQLite database (creating both if they don't exist), one
+// row per process with the algorithm and workload it came from, so the results of many runs can be queried with SQL.
+// This calls the system's SQLite C library directly, the same way main calls signal(), so the default build doesn't
+// depend on it. It is only built with "--features sqlite"
+// input: database path, algorithm name, workload name, borrowed Vector of Processes, output: Result, Err is SQLite's message
+#[cfg(feature = "sqlite")]
+pub fn write_sqlite(db_path: &str, algorithm: &str, workload_name: &str, processes: &Vec<Process>) -> Result<(), String> {
+    use std::ffi::{c_char, c_int, c_void, CStr, CString};
+    #[link(name = "sqlite3")]
+    extern "C" {
+        fn sqlite3_open(filename: *const c_char, db: *mut *mut c_void) -> c_int;
+        fn sqlite3_exec(db: *mut c_void, sql: *const c_char, callback: *const c_void, arg: *mut c_void, errmsg: *mut *mut c_char) -> c_int;
+        fn sqlite3_free(ptr: *mut c_void);
+        fn sqlite3_close(db: *mut c_void) -> c_int;
+    }
+
+    // text goes inside '' in SQL, a ' in the text is written twice
+    let quote = |s: &str| format!("'{}'", s.replace('\'', "''"));
+    // everything in one transaction, so a run either adds all its rows or none
+    let mut sql = String::from("BEGIN; CREATE TABLE IF NOT EXISTS results (pid INTEGER, arrival REAL, duration REAL, \
+        first_run REAL, completion REAL, algorithm TEXT, workload_name TEXT);");
+    for p in processes {
+        sql.push_str(&format!(" INSERT INTO results VALUES ({}, {}, {}, {}, {}, {}, {});",
+            p.pid, p.arrival, p.duration, p.first_run, p.completion, quote(algorithm), quote(workload_name)));
+    }
+    sql.push_str(" COMMIT;");
+
+    // C strings end with a 0 byte, CString adds it
+    let path = CString::new(db_path).map_err(|e| e.to_string())?;
+    let sql = CString::new(sql).map_err(|e| e.to_string())?;
+    unsafe {
+        let mut db = std::ptr::null_mut();
+        if sqlite3_open(path.as_ptr(), &mut db) != 0 {
+            sqlite3_close(db);
+            return Err(format!("could not open {db_path}"));
+        }
+        let mut errmsg = std::ptr::null_mut();
+        let code = sqlite3_exec(db, sql.as_ptr(), std::ptr::null(), std::ptr::null_mut(), &mut errmsg);
+        // sqlite leaves errmsg null when it has no message for the error (it can't make one if it ran out of memory)
+        let result = match code {
+            0 => Ok(()),
+            _ if errmsg.is_null() => Err(format!("sqlite error code {code}")),
+            _ => Err(CStr::from_ptr(errmsg).to_string_lossy().into_owned()),
+        };
+        sqlite3_free(errmsg as *mut c_void);
+        sqlite3_close(db);
+        result
+    }
+}
+
+//----------WORKLOAD GENERATION-----------
+
+
+// a small pseudo random number generator (splitmix64). The standard library doesn't come with one, and writing it here
+// means the same seed always gives the same workload, which is what makes generated workloads reproducible
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    // next random 64 bit number. wrapping_add and wrapping_mul let the numbers overflow on purpose,
+    // by default Rust panics on overflow in debug builds
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    // random float that is at least 0 and less than 1
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // random integer between low and high, including both
+    pub fn range(&mut self, low: i32, high: i32) -> i32 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i32
+    }
+
+    // random number from an exponential distribution with the given mean (inverse transform sampling)
+    pub fn exponential(&mut self, mean: f64) -> f64 {
+        -mean * (1.0 - self.next_f64()).ln()
+    }
+}
+
+// generates n processes where the gap between arrivals is uniformly random between 0 and max_gap and the
+// duration is uniformly random between 1 and max_duration. With a jitter above 0 every arrival is then moved by a
+// random amount between -jitter and jitter (but not before 0), and the processes are sorted again by the moved
+// arrivals and renumbered. The jitter is drawn after the whole nominal schedule, so the same seed gives the same
+// nominal arrivals and durations with or without it
+// input: number of processes, max gap, max duration, jitter, seed, output: Vector of Processes
+pub fn generate_workload(n: usize, max_gap: i32, max_duration: i32, jitter: i32, seed: u64) -> Vec<Process> {
+    let mut rng = Rng::new(seed);
+    let mut arrival = 0;
+    let mut wkld = Vec::new();
+    for pid in 0..n {
+        let mut p = Process::new(arrival, rng.range(1, max_duration), 0, 0);
+        p.pid = pid;
+        wkld.push(p);
+        arrival += rng.range(0, max_gap);
+    }
+    if jitter > 0 {
+        for p in wkld.iter_mut() {
+            p.arrival = (p.arrival + rng.range(-jitter, jitter) as f32).max(0.0);
+        }
+        // a stable sort, so processes that land on the same arrival keep their nominal order
+        wkld.sort_by_key(|p| OrderedF32(p.arrival));
+        for (pid, p) in wkld.iter_mut().enumerate() {
+            p.pid = pid;
+        }
+    }
+    wkld
+}
+
+// generates n processes arriving as a Poisson process, so the gaps between arrivals are exponential with mean lambda,
+// and durations are exponential with mean mean_service. The workload format only has whole numbers, so times are
+// rounded, and durations are at least 1
+// input: number of processes, mean inter-arrival time, mean duration, seed, output: Vector of Processes
+pub fn generate_poisson_workload(n: usize, lambda: f64, mean_service: f64, seed: u64) -> Vec<Process> {
+    let mut rng = Rng::new(seed);
+    let mut time: f64 = 0.0;
+    let mut wkld = Vec::new();
+    for pid in 0..n {
+        let duration = rng.exponential(mean_service).round().max(1.0);
+        let mut p = Process::new(time.round(), duration, 0.0, 0.0);
+        p.pid = pid;
+        wkld.push(p);
+        time += rng.exponential(lambda);
+    }
+    wkld
+}
+
+// generates n processes arriving as a Poisson process like generate_poisson_workload, but every duration is service,
+// the M/D/1 queue of queueing theory. Its average queueing delay has a formula to check the simulator against
+// input: number of processes, mean inter-arrival time, duration, seed, output: Vector of Processes
+pub fn generate_md1_workload(n: usize, lambda: f64, service: f64, seed: u64) -> Vec<Process> {
+    let mut rng = Rng::new(seed);
+    let mut time: f64 = 0.0;
+    let mut wkld = Vec::new();
+    for pid in 0..n {
+        let mut p = Process::new(time.round(), service.round().max(1.0), 0.0, 0.0);
+        p.pid = pid;
+        wkld.push(p);
+        time += rng.exponential(lambda);
+    }
+    wkld
+}
+
+// randomly reorders each run of processes that arrive at the same time, the same seed always gives the same order.
+// read_workload keeps equal arrivals in file order, this is for seeing how much the results depend on that order.
+// It uses the Fisher-Yates shuffle (swap each position with a random one at or before it) within every run. The
+// algorithms break ties by pid, so the pids of a run are handed out again in the new order, the same as if the lines
+// had been written in that order in the file. A depends_on follows its process to the new pid
+// input: mutably borrowed Vector of Processes sorted by arrival, seed, output: None
+pub fn shuffle_ties(wkld: &mut Vec<Process>, seed: u64) {
+    let mut rng = Rng::new(seed);
+    // old pid -> new pid of every process that moved
+    let mut renumbered: HashMap<usize, usize> = HashMap::new();
+    let mut start = 0;
+    while start < wkld.len() {
+        let mut end = start + 1;
+        while end < wkld.len() && wkld[end].arrival == wkld[start].arrival {
+            end += 1;
+        }
+        for i in (start + 1..end).rev() {
+            let j = rng.range(start as i32, i as i32) as usize;
+            wkld.swap(i, j);
+        }
+        let mut pids: Vec<usize> = wkld[start..end].iter().map(|p| p.pid).collect();
+        pids.sort();
+        for (p, pid) in wkld[start..end].iter_mut().zip(pids) {
+            renumbered.insert(p.pid, pid);
+            p.pid = pid;
+        }
+        start = end;
+    }
+    for p in wkld.iter_mut() {
+        p.depends_on = p.depends_on.map(|pid| renumbered.get(&pid).copied().unwrap_or(pid));
+    }
+}
+
+// greatest common divisor, for the hyperperiod
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// calculate the hyperperiod of the workload, the least common multiple of every period, after which the pattern of
+// periodic arrivals repeats
+// input: borrowed Vector of Processes, output: Option, the hyperperiod, None if no process has a period
+pub fn hyperperiod(wkld: &Vec<Process>) -> Option<f32> {
+    let periods: Vec<u64> = wkld.iter().filter_map(|p| p.period).map(|period| period as u64).collect();
+    if periods.is_empty() {
+        return None;
+    }
+    Some(periods.into_iter().fold(1, |lcm, period| lcm / gcd(lcm, period) * period) as f32)
+}
+
+// turns every periodic task into its jobs, one process for every time it arrives from its first arrival until the
+// hyperperiod. A job's deadline is the task's next arrival, so every job has to complete before the next one comes,
+// and it keeps the task's hard_deadline. A task with its own deadline keeps it for the first job, and every later job
+// is due the same time after its arrival. Processes without a period are copied as they are. The jobs get new pids in
+// arrival order and remember the pid of their task in task. A depends_on follows its process to the new pid, and one
+// on a periodic task waits for the task's first job
+// input: borrowed Vector of Processes, output: Vector of Processes sorted by arrival
+pub fn expand_periodic(wkld: &Vec<Process>) -> Vec<Process> {
+    let end = match hyperperiod(wkld) {
+        Some(end) => end,
+        None => return wkld.clone(),
+    };
+    let mut jobs: Vec<Process> = Vec::new();
+    for p in wkld {
+        let period = match p.period {
+            Some(period) => period,
+            None => {
+                jobs.push(p.clone());
+                continue;
+            }
+        };
+        let mut arrival = p.arrival;
+        while arrival < end {
+            let deadline = arrival + p.deadline.map_or(period, |deadline| deadline - p.arrival);
+            jobs.push(Process { arrival, deadline: Some(deadline), task: Some(p.pid), ..p.clone() });
+            arrival += period;
+        }
+    }
+    // the sort is stable, so jobs that arrive together stay in the order of their tasks' lines
+    jobs.sort_by_key(|p| OrderedF32(p.arrival));
+    // old pid -> new pid, a task's first job is the first one to get a new pid
+    let mut renumbered: HashMap<usize, usize> = HashMap::new();
+    for (pid, job) in jobs.iter_mut().enumerate() {
+        renumbered.entry(job.pid).or_insert(pid);
+        job.pid = pid;
+    }
+    for job in jobs.iter_mut() {
+        job.depends_on = job.depends_on.map(|pid| renumbered.get(&pid).copied().unwrap_or(pid));
+    }
+    jobs
+}
+
+// prints how many jobs of each periodic task missed their deadline, as a count and a rate
+// input: borrowed Vector of Processes, output: None
+pub fn show_task_misses(processes: &Vec<Process>) {
+    let mut tasks: Vec<usize> = processes.iter().filter_map(|p| p.task).collect();
+    tasks.sort();
+    tasks.dedup();
+    println!("Deadline Misses per Task:");
+    for task in tasks {
+        let jobs: Vec<&Process> = processes.iter().filter(|p| p.task == Some(task)).collect();
+        let missed = jobs.iter().filter(|p| p.deadline.is_some_and(|deadline| p.completion > deadline)).count();
+        println!("\ttask {task}: {missed} of {} jobs missed ({}%)", jobs.len(), missed as f32 / jobs.len() as f32 * 100.0);
+    }
+}
+
+// prints a workload in the same format read_workload reads, so the output can be redirected into a file
+// input: borrowed Vector of Processes, output: None
+pub fn print_workload(processes: &Vec<Process>) {
+    for p in processes {
+        println!("{}", workload_line(p));
+    }
+}
+
+// the optional columns of a process that aren't at their defaults, as (name, value) pairs in the order the README
+// lists them. The value is written the way the whitespace format writes it, bursts as "2,2,8"
+// input: borrowed Process, output: Vector of (column name, value)
+fn workload_columns(p: &Process) -> Vec<(&'static str, String)> {
+    let mut columns = Vec::new();
+    if p.bursts.len() > 1 {
+        let bursts: Vec<String> = p.bursts.iter().map(|b| b.to_string()).collect();
+        columns.push(("bursts", bursts.join(",")));
+    }
+    if !p.preemptible {
+        columns.push(("preemptible", "false".to_string()));
+    }
+    if let Some(group) = p.group {
+        columns.push(("group", group.to_string()));
+    }
+    if p.priority != 0 {
+        columns.push(("priority", p.priority.to_string()));
+    }
+    if p.weight != 1.0 {
+        columns.push(("weight", p.weight.to_string()));
+    }
+    if p.start_level != 0 {
+        columns.push(("start_level", p.start_level.to_string()));
+    }
+    if let Some(budget) = p.cpu_budget {
+        columns.push(("cpu_budget", budget.to_string()));
+    }
+    if let Some(interval) = p.yield_interval {
+        columns.push(("yield_interval", interval.to_string()));
+    }
+    if let Some(cpu) = p.affinity {
+        columns.push(("affinity", cpu.to_string()));
+    }
+    if let Some(period) = p.period {
+        columns.push(("period", period.to_string()));
+    }
+    if let Some(pid) = p.depends_on {
+        columns.push(("depends_on", pid.to_string()));
+    }
+    if p.memory != 0.0 {
+        columns.push(("memory", p.memory.to_string()));
+    }
+    if let Some(deadline) = p.deadline {
+        columns.push(("deadline", deadline.to_string()));
+    }
+    if p.hard_deadline {
+        columns.push(("hard_deadline", "true".to_string()));
+    }
+    columns
+}
+
+// one line of a whitespace format workload, like "0 12 bursts=2,2,8 priority=1"
+// input: borrowed Process, output: String
+fn workload_line(p: &Process) -> String {
+    let mut line = format!("{} {}", p.arrival, p.duration);
+    for (name, value) in workload_columns(p) {
+        line += &format!(" {name}={value}");
+    }
+    line
+}
+
+// writes a workload to a file that read_workload reads back into the same processes, as JSON if the path ends in
+// .json, in the binary format (see write_binary_workload) if it ends in .bin and in the whitespace format otherwise. The processes go in pid order, so each one is on the line (or at the
+// position in the array) its pid came from and keeps its pid when the file is read. Arrivals are always absolute
+// input: path, borrowed Vector of Processes, output: Result, Err if the file couldn't be written
+pub fn write_workload(path: &str, processes: &Vec<Process>) -> io::Result<()> {
+    if path.ends_with(".bin") {
+        return write_binary_workload(path, processes);
+    }
+    let mut in_order: Vec<&Process> = processes.iter().collect();
+    in_order.sort_by_key(|p| p.pid);
+    let text = if path.ends_with(".json") {
+        let objects: Vec<String> = in_order.iter().map(|p| {
+            let mut fields = vec![format!("\"arrival\": {}", p.arrival), format!("\"duration\": {}", p.duration)];
+            for (name, value) in workload_columns(p) {
+                // bursts are the one array, everything else is already a JSON number or true/false
+                let value = if name == "bursts" { format!("[{}]", value.replace(',', ", ")) } else { value };
+                fields.push(format!("\"{name}\": {value}"));
+            }
+            format!("    {{{}}}", fields.join(", "))
+        }).collect();
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    } else {
+        in_order.iter().map(|p| workload_line(p) + "\n").collect()
+    };
+    std::fs::write(path, text)
+}
+
+// writes a workload in the binary format parse_binary_workload reads, in pid order like write_workload. The format
+// only has arrivals and durations, so the optional columns are left out
+// input: path, borrowed Vector of Processes, output: Result, Err if the file couldn't be written
+pub fn write_binary_workload(path: &str, processes: &Vec<Process>) -> io::Result<()> {
+    let mut in_order: Vec<&Process> = processes.iter().collect();
+    in_order.sort_by_key(|p| p.pid);
+    let mut bytes = Vec::with_capacity(4 + in_order.len() * 8);
+    bytes.extend_from_slice(&(in_order.len() as u32).to_le_bytes());
+    for p in in_order {
+        bytes.extend_from_slice(&p.arrival.to_le_bytes());
+        bytes.extend_from_slice(&p.duration.to_le_bytes());
+    }
+    std::fs::write(path, bytes)
+}
+
+//----------ALGORITHMS-----------
+
+// an f32 that can be compared with cmp, so the heaps and sorts can order times and durations without unwrapping
+// partial_cmp, which has no answer for NaN. NaN is greater than every number and equal to itself, so a NaN sorts last (and
+// comes out of the heaps, which put the smallest on top, last) instead of being a panic waiting to happen. Everything
+// else compares the way partial_cmp does, so -0 and 0 are still equal
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedF32(pub f32);
+
+impl PartialEq for OrderedF32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedF32 {}
+
+impl PartialOrd for OrderedF32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            // neither is NaN, so partial_cmp always has an answer
+            (false, false) => self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+// the order the heaps of the algorithms put processes in when nothing else tells them apart: the earliest arrival on
+// top, and for equal arrivals the lowest pid. The heap is a max heap, so this says p1 is "less" than p2 when p1
+// should come after it. binary_heap_plus doesn't keep equal elements in the order they were pushed, so without the pid
+// two processes arriving together could come out in either order and the same workload could get different schedules.
+// Every heap ends its comparison with this so nothing is ever equal. Tie-breaks per algorithm:
+// - sjf, stcf: shortest remaining time, then this (the running process wins against an equally short arrival). ljf the
+//   same with the longest remaining time
+// - rr, mlfq: this decides the order processes that arrive together join the queue in (mlfq puts arrivals at the front
+//   of the first level, so among those the highest pid ends up running first)
+// - priority: highest priority, then this. spn: smallest estimate, then this. edf: earliest deadline, then this
+// - aging: highest effective priority, then this
+// - hybrid: like sjf in its sjf windows, in its rr windows like rr
+// - fifo, gang and cfs sort or compare by pid on their own
+// input: two borrowed Processes, output: Ordering
+fn by_arrival(p1: &Process, p2: &Process) -> Ordering {
+    OrderedF32(p2.arrival).cmp(&OrderedF32(p1.arrival)).then(p2.pid.cmp(&p1.pid))
+}
+
+
+// runs FIFO algorithm
+// input: borrowed Vector of Processes, output: ScheduleResult
+pub fn fifo(workload: &Vec<Process>) -> ScheduleResult {
+    // Note here, that all these variables are declared with mut or mutable, by default values are not mutable. This ensures saftey as well
+    // by preventing unecessary changes to references.
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    // first come first served, processes that are ready at the same time are served in pid order (the order they were
+    // written in the workload file), not whatever order the workload happens to be in. A process is ready when it
+    // arrives, or with depends_on when its dependency completes if that is later, so the queue holds (ready time, process)
+    let mut queue = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![], |a: &(f32, Process), b: &(f32, Process)| {
+        OrderedF32(b.0).cmp(&OrderedF32(a.0)).then(b.1.pid.cmp(&a.1.pid))
+    });
+    // processes waiting for their dependency to complete, by the pid they depend on
+    let mut dependents: HashMap<usize, Vec<Process>> = HashMap::new();
+    let pids: HashSet<usize> = workload.iter().map(|p| p.pid).collect();
+    for process in workload {
+        let p = process.reset();
+        match p.depends_on {
+            // a dependency on a pid that isn't in the workload (a line --lenient skipped) can never complete, so it is ignored
+            Some(pid) if pids.contains(&pid) => dependents.entry(pid).or_default().push(p),
+            _ => queue.push((p.arrival, p)),
+        }
+    }
+    let mut curr_time = workload.first().unwrap().arrival;
+
+    while let Some((ready, mut p)) = queue.pop() {
+        // if the CPU finished everything before this process was ready, it sits idle until then
+        curr_time = curr_time.max(ready);
+        p.first_run = curr_time;
+        curr_time += p.duration;
+        p.completion = curr_time;
+        add_segment(&mut segments, p.first_run, p.completion, p.pid);
+        for dependent in dependents.remove(&p.pid).unwrap_or_default() {
+            queue.push((dependent.arrival.max(curr_time), dependent));
+        }
+        complete.push(p);
+    }
+    // note here, we give up ownership of complete to whatever reference points to this function call
+    // also note, because this is the last statement in the function, this is returned without an explicit call to return
+    // this is the idiomatic way to return, calling return if the thing you are returning is the last statement works but is 
+    // considered to be not idiomatic. Notice here that there is no semicolon either, if you added a semicolon, this function would
+    // instead return a None type. 
+    ScheduleResult { processes: complete, segments, incomplete: Vec::new(), queue_lengths: Vec::new(), segment_cpus: Vec::new() }
+}
+
+// runs FIFO algorithm on a whitespace format workload as it is read, one line at a time, instead of reading the whole
+// file into a Vector first. FIFO only ever needs the next process to arrive, so nothing but the current time has to be
+// kept and a file with millions of processes takes no more memory than one with 10. Because it can't sort, the file
+// has to already be in arrival order (lines that arrive together still go in file order, like in fifo), and the schedule
+// is then the same one fifo makes
+// input: BufRead over the workload, arrival format, closure that gets each process as it completes, output: Result,
+// the first problem with the workload after everything before it was scheduled
+pub fn fifo_streaming<R: BufRead, F: FnMut(Process)>(reader: R, arrivals: ArrivalFormat, mut emit: F) -> Result<(), WorkloadError> {
+    let mut curr_time = f32::MIN;
+    // None until the first line is read
+    let mut last_arrival: Option<f32> = None;
+    for (pid, line) in reader.lines().enumerate() {
+        let mut p = parse_line(pid, &line.map_err(|e| WorkloadError::Io(e.to_string()))?)?;
+        if arrivals == ArrivalFormat::Delta {
+            p.arrival += last_arrival.unwrap_or(0.0);
+        }
+        if last_arrival.is_some_and(|last| p.arrival < last) {
+            return Err(WorkloadError::Unsorted { line: pid + 1 });
+        }
+        last_arrival = Some(p.arrival);
+        curr_time = curr_time.max(p.arrival);
+        p.first_run = curr_time;
+        curr_time += p.duration;
+        p.completion = curr_time;
+        emit(p);
+    }
+    if last_arrival.is_none() {
+        return Err(WorkloadError::Empty);
+    }
+    Ok(())
+}
+
+// runs the offline optimum for average completion time, for comparing the other algorithms against. It ignores when
+// the processes arrive and treats them all as available at the earliest arrival, then runs them shortest first (ties
+// in pid order). With everything available at once no order does better: if a longer process ran right before a
+// shorter one, swapping them makes the shorter one complete earlier by more than the longer one completes later. The
+// real algorithms have to wait for arrivals, so this is a lower bound for them, and it is the same schedule as sjf when
+// every process arrives at once. first_run can come before arrival, so it isn't a schedule that validate_schedule accepts
+// input: borrowed Vector of Processes, output: ScheduleResult
+pub fn optimal_offline(workload: &Vec<Process>) -> ScheduleResult {
+    let mut order: Vec<Process> = workload.iter().map(Process::reset).collect();
+    order.sort_by(|a, b| OrderedF32(a.duration).cmp(&OrderedF32(b.duration)).then(a.pid.cmp(&b.pid)));
+    let mut curr_time = workload.iter().fold(f32::MAX, |acc, p| acc.min(p.arrival));
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    for mut p in order {
+        p.first_run = curr_time;
+        curr_time += p.duration;
+        p.completion = curr_time;
+        p.remaining_time = 0.0;
+        add_segment(&mut segments, p.first_run, p.completion, p.pid);
+        complete.push(p);
+    }
+    ScheduleResult { processes: complete, segments, incomplete: Vec::new(), queue_lengths: Vec::new(), segment_cpus: Vec::new() }
+}
+
+// runs SJF algorithm, shortest_job without preemption
+// input: borrowed Vector of Processes, output: ScheduleResult
+pub fn sjf(workload:  &Vec<Process>) -> ScheduleResult {
+    shortest_job(workload, &SimConfig::default(), false, false)
+}
+
+// runs LJF (longest job first) algorithm, shortest_job without preemption picking the longest process instead. It is
+// the worst order for average turnaround, every short job waits behind the long ones, which makes it a contrast to SJF
+// input: borrowed Vector of Processes, output: ScheduleResult
+pub fn ljf(workload: &Vec<Process>) -> ScheduleResult {
+    shortest_job(workload, &SimConfig::default(), false, true)
+}
+
+// runs non-preemptive priority scheduling, whenever the CPU is free it runs the ready process with the highest
+// priority (lowest number) to completion. Same structure as SJF, just ordered by priority instead of duration
+// input: borrowed Vector of Processes, output: ScheduleResult
+pub fn priority(workload: &Vec<Process>) -> ScheduleResult {
+    let mut wkld: VecDeque<Process> = workload.iter().map(Process::reset).collect();
+    let n = wkld.len();
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut curr_time = wkld.front().unwrap().arrival;
+    let mut ready = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![],
+        |p1: &Process, p2: &Process| p2.priority.cmp(&p1.priority).then(by_arrival(p1, p2)));
+
+    while complete.len() != n {
+        while !wkld.is_empty() && curr_time >= wkld.front().unwrap().arrival {
+            ready.push(wkld.pop_front().unwrap());
+        }
+
+        // nothing is ready, skip ahead to the next arrival
+        if ready.is_empty() {
+            curr_time = wkld.front().unwrap().arrival;
+            continue;
+        }
+
+        let mut p = ready.pop().unwrap();
+        p.first_run = curr_time;
+        curr_time += p.duration;
+        p.completion = curr_time;
+        add_segment(&mut segments, p.first_run, p.completion, p.pid);
+        complete.push(p);
+    }
+
+    ScheduleResult { processes: complete, segments, incomplete: Vec::new(), queue_lengths: Vec::new(), segment_cpus: Vec::new() }
+}
+
+// runs priority scheduling with aging, a preemptive version of priority where a process's effective priority is its
+// priority number minus aging_rate for every tick it has been waiting since it arrived or last ran. Every tick the
+// ready process with the highest effective priority (lowest number) runs, so a low priority process that keeps losing
+// gets a little better every tick it waits and eventually gets to run, instead of starving behind a stream of higher
+// priority arrivals. Running resets the wait, so a process that was just on the CPU is back at its own priority.
+// Every tick does speed work, and like stcf a process only pays the dispatch overhead when it takes the CPU over
+// input: borrowed Vector of Processes, borrowed SimConfig (aging_rate, speed, dispatch_overhead), output: ScheduleResult
+pub fn aging_priority(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let aging_rate = config.aging_rate;
+    // first_run is -1 until the process first runs, like in rr
+    let mut todo: VecDeque<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    let n = todo.len();
+    // the effective priority changes every tick, so the ready set is a Vector searched each tick instead of a heap.
+    // Each process is paired with the time it started waiting
+    let mut ready: Vec<(Process, f32)> = Vec::new();
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut queue_lengths = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
+
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            let p = todo.pop_front().unwrap();
+            let arrival = p.arrival;
+            ready.push((p, arrival));
+        }
+
+        // nothing is ready, skip ahead to the next arrival
+        if ready.is_empty() {
+            curr_time = todo.front().unwrap().arrival;
+            continue;
+        }
+        queue_lengths.push((curr_time, ready.len()));
+
+        let effective = |(p, since): &(Process, f32)| p.priority as f32 - aging_rate * (curr_time - since);
+        // by_arrival is written for the max heaps, so its arguments are swapped for min_by
+        let next = (0..ready.len()).min_by(|&i, &j| {
+            OrderedF32(effective(&ready[i])).cmp(&OrderedF32(effective(&ready[j]))).then(by_arrival(&ready[j].0, &ready[i].0))
+        }).unwrap();
+        let (mut p, _) = ready.swap_remove(next);
+        if is_new_dispatch(&segments, p.pid, curr_time) {
+            curr_time += config.dispatch_overhead;
+        }
+        if p.first_run == -1.0 {
+            p.first_run = curr_time;
+        }
+        run_tick(&mut p, config.speed);
+        add_segment(&mut segments, curr_time, curr_time + 1.0, p.pid);
+        curr_time += 1.0;
+
+        if p.remaining_time <= 0.0 {
+            p.completion = curr_time;
+            complete.push(p);
+        } else {
+            // it starts waiting again now, at its own priority
+            ready.push((p, curr_time));
+        }
+    }
+
+    // only non-empty if the loop was interrupted
+    let mut incomplete: Vec<Process> = ready.into_iter().map(|(p, _)| p).chain(todo).collect();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// runs a scheduling policy given as a closure, so a new policy can be tried without writing a whole algorithm. This
+// does the bookkeeping every algorithm here has to do (arrivals, idle time, first_run, completion, segments and queue
+// lengths) and decide only has to pick what runs. decide is called at every decision point, whenever a process arrives
+// or completes, with the current time and the ready processes (their remaining_time shows how far along they are,
+// arrival order with ties in pid order), and returns the index in that slice of the one to run. It runs until it
+// completes or the next process arrives, whichever is first, so a policy can preempt on an arrival (STCF) but not on a
+// timer (RR). Like fifo, a process runs for exactly its duration, groups and depends_on are ignored
+// input: borrowed Vector of Processes, closure from time and ready processes to an index in them, output: ScheduleResult
+pub fn custom_schedule<F: FnMut(f32, &[Process]) -> usize>(workload: &Vec<Process>, mut decide: F) -> ScheduleResult {
+    let mut todo: Vec<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    todo.sort_by(|a, b| by_arrival(b, a));
+    let mut todo = VecDeque::from(todo);
+    let n = todo.len();
+    let mut ready: Vec<Process> = Vec::new();
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut queue_lengths = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
+
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            ready.push(todo.pop_front().unwrap());
+        }
+
+        // nothing is ready, skip ahead to the next arrival
+        if ready.is_empty() {
+            curr_time = todo.front().unwrap().arrival;
+            continue;
+        }
+        queue_lengths.push((curr_time, ready.len()));
+
+        // a Vector, not a VecDeque, so decide gets one slice in order
+        let next = decide(curr_time, &ready);
+        let p = &mut ready[next];
+        if p.first_run == -1.0 {
+            p.first_run = curr_time;
+        }
+        let end = match todo.front() {
+            Some(q) => (curr_time + p.remaining_time).min(q.arrival),
+            None => curr_time + p.remaining_time,
+        };
+        p.remaining_time -= end - curr_time;
+        add_segment(&mut segments, curr_time, end, p.pid);
+        curr_time = end;
+
+        if p.remaining_time <= 0.0 {
+            // remove instead of swap_remove, so the rest stay in arrival order
+            let mut p = ready.remove(next);
+            p.completion = curr_time;
+            complete.push(p);
+        }
+    }
+
+    // only non-empty if the loop was interrupted
+    let mut incomplete: Vec<Process> = ready.into_iter().chain(todo).collect();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// runs gang scheduling on a machine with num_cpus CPUs. Processes with the same group are a gang, a parallel job whose
+// members all have to run at the same time, so a gang is only dispatched once every member has arrived and there is a
+// free CPU for each of them. A process without a group is a gang of one. Gangs are served first come first served, by
+// when their last member arrived, and each member runs to completion on its own CPU. Because several CPUs run at once
+// the segments of this schedule can overlap. A gang with more members than there are CPUs can never run, it ends up
+// in incomplete
+// input: borrowed Vector of Processes, number of CPUs, output: ScheduleResult
+pub fn gang(workload: &Vec<Process>, num_cpus: usize) -> ScheduleResult {
+    let mut gangs: Vec<Vec<Process>> = Vec::new();
+    for p in workload.iter().map(Process::reset) {
+        match gangs.iter_mut().find(|g| p.group.is_some() && g[0].group == p.group) {
+            Some(g) => g.push(p),
+            None => gangs.push(vec![p]),
+        }
+    }
+    // fold goes through an iterator carrying a value along, here the latest arrival so far
+    let ready_time = |g: &Vec<Process>| g.iter().map(|p| p.arrival).fold(f32::MIN, f32::max);
+    gangs.sort_by(|a, b| OrderedF32(ready_time(a)).cmp(&OrderedF32(ready_time(b))).then(a[0].pid.cmp(&b[0].pid)));
+
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut segment_cpus = Vec::new();
+    let mut incomplete = Vec::new();
+    let mut curr_time = workload.first().unwrap().arrival;
+    // the time each CPU finishes what it is running
+    let mut cpu_free = vec![curr_time; num_cpus];
+
+    for mut g in gangs {
+        if g.len() > num_cpus {
+            incomplete.extend(g);
+            continue;
+        }
+        // the gang can start once g.len() CPUs are free (and never before the gang ahead of it started), then its
+        // members go on the lowest numbered CPUs that are free by then
+        let mut free_times = cpu_free.clone();
+        free_times.sort_by_key(|&t| OrderedF32(t));
+        curr_time = curr_time.max(ready_time(&g)).max(free_times[g.len() - 1]);
+        let free: Vec<usize> = (0..num_cpus).filter(|&cpu| cpu_free[cpu] <= curr_time).collect();
+        for (p, &cpu) in g.iter_mut().zip(&free) {
+            p.first_run = curr_time;
+            p.completion = curr_time + p.duration;
+            cpu_free[cpu] = p.completion;
+            segments.push((p.first_run, p.completion, p.pid));
+            segment_cpus.push(cpu);
+        }
+        complete.extend(g);
+    }
+
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths: Vec::new(), segment_cpus }
+}
+
+// runs STCF algorithm, shortest_job with preemption
+// input: borrowed Vector of Processes, borrowed SimConfig, output: ScheduleResult
+pub fn stcf(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    shortest_job(workload, config, true, false)
+}
+
+// runs shortest job scheduling, whenever it picks a process it picks the one with the least time left. With preemptive
+// it is STCF, every tick the shortest process runs so a shorter arrival takes the CPU. Without it is SJF, a process
+// that starts keeps the CPU until it completes, so it runs it all in one step instead of a tick at a time, which keeps
+// fractional and zero durations exact and a long process fast. longest turns the comparison around to pick the most
+// time left (LJF)
+// input: borrowed Vector of Processes, borrowed SimConfig, whether to preempt, whether to pick the longest, output:
+// ScheduleResult
+pub fn shortest_job(workload: &Vec<Process>, config: &SimConfig, preemptive: bool, longest: bool) -> ScheduleResult {
+    let wkld: Vec<Process> = workload.iter().map(Process::reset).collect();
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut todo = binary_heap_plus::BinaryHeap::from_vec_cmp(wkld.to_vec(), by_arrival);
+    // equal remaining times go to the one that arrived first, so the running process keeps the CPU against an
+    // arrival that is only as short as it is, then to the lower pid
+    let mut in_progress_dur = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![], 
+        move |p1: &Process, p2 :&Process| {
+            let by_length = OrderedF32(p2.remaining_time).cmp(&OrderedF32(p1.remaining_time));
+            if longest { by_length.reverse() } else { by_length }.then(by_arrival(p1, p2))
+        });
+
+    let mut curr_time = todo.peek().unwrap().arrival;
+    let mut init = todo.peek().unwrap().clone();
+    init.first_run = -1.0;
+    todo.pop();
+
+    // a process that is in the middle of running and can't be preempted (everything without preemptive, or a
+    // non-preemptible process), it stays on the CPU instead of going back in the heap where a shorter arrival could get
+    // picked over it. There is no time slice, so it holds the CPU until it completes
+    let mut holding: Option<Process> = None;
+    let mut queue_lengths = Vec::new();
+    if preemptive {
+        in_progress_dur.push(init);
+    } else {
+        // without preemption the first process starts right away, before the others arriving at the same time are
+        // looked at. This is how sjf has always worked, keeping it keeps its schedules the same
+        holding = Some(init);
+    }
+
+    while !in_progress_dur.is_empty() || holding.is_some() {
+        // ctrl-c was pressed, stop and return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        while !todo.is_empty() && curr_time >= todo.peek().unwrap().arrival {
+            let p = todo.pop().unwrap();
+            let mut p_clone = p.clone();
+            p_clone.first_run = -1.0;
+            in_progress_dur.push(p_clone);
+        }
+
+        // without preemption there are no ticks to sample the queue at
+        if preemptive {
+            queue_lengths.push((curr_time, in_progress_dur.len() + holding.iter().len()));
+        }
+        let mut p = match holding.take() {
+            Some(p) => p,
+            None => in_progress_dur.pop().unwrap(),
+        };
+        if is_new_dispatch(&segments, p.pid, curr_time) {
+            curr_time += config.dispatch_overhead;
+        }
+        if p.first_run == -1.0 {
+            p.first_run = curr_time;
+        }
+        // each tick does speed units of work, with a speed that doesn't divide the duration the last tick overshoots
+        // past 0, so the check below is <= 0 rather than == 0
+        let step = match preemptive {
+            true => step_length(config, &p, curr_time, todo.peek().map(|q| q.arrival)),
+            false => p.remaining_time / config.speed,
+        };
+        run_tick(&mut p, config.speed * step);
+        add_segment(&mut segments, curr_time, curr_time + step, p.pid);
+        curr_time += step;
+
+        if p.remaining_time <= 0.0 {
+            p.completion = curr_time;
+            complete.push(p);
+            show_completion(config, &complete);
+        } else if !preemptive || !p.preemptible {
+            holding = Some(p);
+        } else {
+            in_progress_dur.push(p);
+        }
+
+        // nothing is left to run, so the CPU would go idle, skip ahead to the next arrival. A process run in one step
+        // can finish after the next arrival, then it is already due and the time stays where it is
+        if in_progress_dur.is_empty() && holding.is_none() && !todo.is_empty() {
+            let mut next = todo.pop().unwrap();
+            curr_time = curr_time.max(next.arrival);
+            next.first_run = -1.0;
+            in_progress_dur.push(next);
+        }
+    }
+
+    // only non-empty if the loop was interrupted, everything still waiting or not arrived yet never finished
+    let mut incomplete = in_progress_dur.into_vec();
+    incomplete.extend(holding);
+    incomplete.extend(todo.into_vec());
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// the processes rr has taken out of its queue because of --events
+#[derive(Clone, Debug, Default)]
+struct Suspended {
+    processes: Vec<Process>, // suspended after they arrived, waiting to be resumed
+    before_arrival: Vec<usize>, // pids suspended before they arrived, they go straight in processes when they arrive
+    intervals: Vec<(usize, f32, f32)>, // (pid, start, end) of every suspension, the end is f32::MAX until it is resumed
+}
+
+impl Suspended {
+    // applies every event from events[next] on that is due by time to the queue. A suspended process is taken out
+    // wherever it is in the queue, a resumed one goes to the back like an arrival. Suspending something that isn't in
+    // the queue means it hasn't arrived yet (or already completed, then it does nothing)
+    fn apply(&mut self, events: &[SuspendEvent], next: &mut usize, time: f32, queue: &mut VecDeque<Process>) {
+        while *next < events.len() && events[*next].time <= time {
+            let e = &events[*next];
+            *next += 1;
+            if e.suspend {
+                if let Some(i) = queue.iter().position(|p| p.pid == e.pid) {
+                    self.processes.push(queue.remove(i).unwrap());
+                    self.intervals.push((e.pid, time, f32::MAX));
+                } else if !self.processes.iter().any(|p| p.pid == e.pid) {
+                    self.before_arrival.push(e.pid);
+                }
+            } else if let Some(i) = self.processes.iter().position(|p| p.pid == e.pid) {
+                queue.push_back(self.processes.remove(i));
+                if let Some(interval) = self.intervals.iter_mut().rev().find(|s| s.0 == e.pid) {
+                    interval.2 = time;
+                }
+            } else {
+                self.before_arrival.retain(|&pid| pid != e.pid);
+            }
+        }
+    }
+
+    // puts a process that just arrived at the back of the queue, unless it was suspended before it got here
+    fn admit(&mut self, p: Process, queue: &mut VecDeque<Process>) {
+        match self.before_arrival.iter().position(|&pid| pid == p.pid) {
+            Some(i) => {
+                self.before_arrival.remove(i);
+                self.intervals.push((p.pid, p.arrival, f32::MAX));
+                self.processes.push(p);
+            }
+            None => queue.push_back(p),
+        }
+    }
+
+    // calculate how long p was suspended between first running and completing. record_ready_wait counts every gap
+    // between p's segments as waiting in the ready queue, this is the part of those gaps it wasn't in the queue at all
+    fn after_first_run(&self, p: &Process) -> f32 {
+        self.intervals.iter()
+            .filter(|s| s.0 == p.pid)
+            .map(|s| (s.2.min(p.completion) - s.1.max(p.first_run)).max(0.0))
+            .sum()
+    }
+}
+
+// runs RR algorithm
+// input: borrowed Vector of Processes, borrowed SimConfig, output: ScheduleResult
+pub fn rr(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    rr_live(workload, config, &mut std::iter::empty())
+}
+
+// the processes arriving on an --live pipe, one line each written like a workload line ("arrival duration" and any
+// optional columns). They get the pids after the workload's, and have to come in arrival order like a streamed file.
+// A bad line is reported and skipped, the pipe keeps going until the other end closes it
+pub struct LiveArrivals<R: BufRead> {
+    lines: io::Lines<R>,
+    line: usize,
+    next_pid: usize,
+    last_arrival: f32,
+}
+
+impl<R: BufRead> LiveArrivals<R> {
+    // input: the pipe (or anything else with lines), the pid for the first process, output: LiveArrivals
+    pub fn new(reader: R, first_pid: usize) -> Self {
+        LiveArrivals { lines: reader.lines(), line: 0, next_pid: first_pid, last_arrival: f32::MIN }
+    }
+}
+
+impl<R: BufRead> Iterator for LiveArrivals<R> {
+    type Item = Process;
+
+    // blocks until the next good line is written, None once the pipe is closed
+    fn next(&mut self) -> Option<Process> {
+        loop {
+            let text = match self.lines.next()? {
+                Ok(text) => text,
+                Err(e) => {
+                    println!("Warning: live input: {e}");
+                    return None;
+                }
+            };
+            self.line += 1;
+            // parse_line gives errors the line number pid + 1
+            let parsed = parse_line(self.line - 1, &text).and_then(|p| match p.arrival < self.last_arrival {
+                true => Err(WorkloadError::Unsorted { line: self.line }),
+                false => Ok(p),
+            });
+            match parsed {
+                Ok(p) => {
+                    self.last_arrival = p.arrival;
+                    self.next_pid += 1;
+                    return Some(Process { pid: self.next_pid - 1, first_run: -1.0, ..p });
+                }
+                Err(e) => println!("Warning: live input {e}"),
+            }
+        }
+    }
+}
+
+// keeps track of how far into the live arrivals rr has read
+struct Live<'a> {
+    input: &'a mut dyn Iterator<Item = Process>,
+    read_until: f32, // the arrival of the last process read, infinity once the input has ended
+}
+
+impl Live<'_> {
+    // reads live arrivals until one arrives after time (or the input ends). They come in arrival order, so once one of
+    // them is after time, everything arriving by time has been read and rr can go on without waiting for the rest
+    // input: time, output: Vector of the processes read
+    fn read(&mut self, time: f32) -> Vec<Process> {
+        let mut read = Vec::new();
+        while self.read_until <= time {
+            match self.input.next() {
+                Some(p) => {
+                    self.read_until = p.arrival;
+                    read.push(p);
+                }
+                None => self.read_until = f32::INFINITY,
+            }
+        }
+        read
+    }
+}
+
+// runs RR algorithm, with processes still arriving from live while it runs (see LiveArrivals). The simulation only
+// moves past a time once live has a process arriving after it (or has ended), so the schedule is the same as running
+// rr on the workload and the live processes together, it just can't run ahead of the input
+// input: borrowed Vector of Processes, borrowed SimConfig, the live arrivals, output: ScheduleResult
+pub fn rr_live(workload: &Vec<Process>, config: &SimConfig, live: &mut dyn Iterator<Item = Process>) -> ScheduleResult {
+    rr_run(RrState::new(workload), config, live, None)
+}
+
+// runs RR algorithm like rr, saving everything it needs to carry on to checkpointing.path as it goes, so a long
+// simulation can be continued later with resume_rr. It stops early if checkpointing says to
+// input: borrowed Vector of Processes, borrowed SimConfig, where and when to checkpoint, output: ScheduleResult
+pub fn rr_checkpointable(workload: &Vec<Process>, config: &SimConfig, checkpointing: &Checkpointing) -> ScheduleResult {
+    rr_run(RrState::new(workload), config, &mut std::iter::empty(), Some(checkpointing))
+}
+
+// carries on the rr run saved in the checkpoint at path until it completes, giving the same result the run would have
+// had without stopping. The quantum, speed, tick, dispatch overhead and events come from the checkpoint, not config,
+// so the rest of the run uses the same ones the first part did. It can keep checkpointing as it goes too
+// input: borrowed Vector of Processes (the workload the run was started on), checkpoint path, borrowed SimConfig,
+// where and when to checkpoint, output: Result, the ScheduleResult of the whole run or what is wrong with the checkpoint
+pub fn resume_rr(workload: &Vec<Process>, path: &str, config: &SimConfig, checkpointing: Option<&Checkpointing>) -> Result<ScheduleResult, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("could not read the checkpoint: {e}"))?;
+    let (state, config) = parse_rr_checkpoint(&text, config).map_err(|e| format!("the checkpoint {e}"))?;
+    let mut pids: Vec<usize> = state.processes().map(|p| p.pid).collect();
+    pids.sort();
+    let mut workload_pids: Vec<usize> = workload.iter().map(|p| p.pid).collect();
+    workload_pids.sort();
+    if pids != workload_pids {
+        return Err(format!("the checkpoint is of a different workload ({} processes, the workload has {})", pids.len(), workload_pids.len()));
+    }
+    Ok(rr_run(state, &config, &mut std::iter::empty(), checkpointing))
+}
+
+// where and when rr_checkpointable and resume_rr save a checkpoint. The file always holds the latest one
+pub struct Checkpointing {
+    pub path: String,
+    pub every: f32, // save at the end of the first time slice after every this much simulated time
+    pub stop_at: Option<f32>, // save and stop at the end of the first time slice at or after this time
+}
+
+// everything rr has to carry from one time slice to the next, which is what a checkpoint saves
+#[derive(Clone, Debug)]
+struct RrState {
+    time: f32,
+    todo: Vec<Process>, // not arrived yet, rr keeps these in a heap so their order doesn't matter
+    queue: VecDeque<Process>,
+    suspended: Suspended, // processes taken out of the queue by --events
+    next_event: usize,
+    complete: Vec<Process>,
+    segments: Vec<(f32, f32, usize)>,
+    queue_lengths: Vec<(f32, usize)>,
+}
+
+impl RrState {
+    // the state before anything has run, the CPU idle at the first arrival
+    fn new(workload: &Vec<Process>) -> Self {
+        RrState {
+            time: workload.iter().fold(f32::MAX, |acc, p| acc.min(p.arrival)),
+            todo: workload.iter().map(Process::reset).collect(),
+            queue: VecDeque::new(),
+            suspended: Suspended::default(),
+            next_event: 0,
+            complete: Vec::new(),
+            segments: Vec::new(),
+            queue_lengths: Vec::new(),
+        }
+    }
+
+    // every process in the state, wherever it is
+    fn processes(&self) -> impl Iterator<Item = &Process> {
+        self.todo.iter().chain(&self.queue).chain(&self.suspended.processes).chain(&self.complete)
+    }
+}
+
+// the loop of rr, rr_live, rr_checkpointable and resume_rr, running from state until everything completes (or
+// checkpointing stops it, or ctrl-c)
+// input: the state to start from, borrowed SimConfig, the live arrivals, where and when to checkpoint, output:
+// ScheduleResult
+fn rr_run(state: RrState, config: &SimConfig, live: &mut dyn Iterator<Item = Process>, checkpointing: Option<&Checkpointing>) -> ScheduleResult {
+    let mut live = Live { input: live, read_until: f32::MIN };
+    let RrState { time: mut curr_time, todo, queue: mut in_progress, mut suspended, mut next_event, mut complete, mut segments, mut queue_lengths } = state;
+    let mut todo = binary_heap_plus::BinaryHeap::from_vec_cmp(todo, by_arrival as fn(&Process, &Process) -> Ordering);
+    let mut next_checkpoint = checkpointing.map_or(f32::INFINITY, |c| curr_time + c.every);
+
+    loop {
+        // nothing is left to run, so the CPU would go idle, skip ahead to the next arrival, or to the next event if that
+        // comes first because it could resume something
+        while in_progress.is_empty() {
+            for p in live.read(curr_time) {
+                todo.push(p);
+            }
+            let next_arrival = todo.peek().map(|p| p.arrival);
+            match (next_arrival, config.events.get(next_event).map(|e| e.time)) {
+                (_, Some(time)) if next_arrival.is_none_or(|arrival| time <= arrival) => {
+                    curr_time = curr_time.max(time);
+                    suspended.apply(&config.events, &mut next_event, curr_time, &mut in_progress);
+                }
+                (Some(_), _) => {
+                    let next = todo.pop().unwrap();
+                    curr_time = next.arrival;
+                    suspended.admit(Process { first_run: -1.0, ..next }, &mut in_progress);
+                }
+                (None, _) => break,
+            }
+        }
+        // Note, pop_front() returns an Option enum, which can either be Some or None. None is similar to null while avoiding having null
+        // this check that that pop_front() pops a Some type and not a None type
+        let mut p = match in_progress.pop_front() {
+            Some(p) => p,
+            None => break,
+        };
+        // ctrl-c was pressed, put p back and return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            in_progress.push_front(p);
+            break;
+        }
+
+        // p runs for one quantum, a tick at a time so processes that arrive partway through the slice still join the
+        // queue when they arrive, ahead of p. The slice ends early if p finishes, or if it yields after running its
+        // yield_interval. Every slice is a dispatch, even if p was the one running before it
+        curr_time += config.dispatch_overhead;
+        let mut slice = 0.0;
+        loop {
+            for p in live.read(curr_time) {
+                todo.push(p);
+            }
+            while !todo.is_empty() && curr_time >= todo.peek().unwrap().arrival {
+                let p = todo.pop().unwrap();
+                let p = Process {
+                    first_run: -1.0,
+                    ..p // Note here, this essentially fills in the rest of the fields with the fields from the original p
+                };
+                suspended.admit(p, &mut in_progress);
+            }
+            // + 1 for p, which is on the CPU
+            queue_lengths.push((curr_time, in_progress.len() + 1));
+
+            if p.first_run == -1.0 {
+                p.first_run = curr_time;
+            }
+            // rr doesn't preempt on an arrival, but with preempt_on_arrival the arrival still joins the queue exactly when it arrives
+            let step = step_length(config, &p, curr_time, todo.peek().map(|q| q.arrival));
+            run_tick(&mut p, config.speed * step);
+            add_segment(&mut segments, curr_time, curr_time + step, p.pid);
+            curr_time += step;
+            slice += step;
+            // the work done so far divided by the speed is how many ticks p has been on the CPU
+            let ran = (p.duration - p.remaining_time) / config.speed;
+            if p.remaining_time > 0.0 && p.cpu_budget.is_some_and(|budget| ran >= budget) {
+                p.budget_exhausted = true;
+            }
+            let yields = p.yield_interval.is_some_and(|interval| slice >= interval);
+            if p.remaining_time <= 0.0 || p.budget_exhausted || yields || slice >= config.quantum {
+                break;
+            }
+        }
+
+        // a process that used up its budget is done even though it has work left, like a cgroup killing it
+        if p.remaining_time <= 0.0 || p.budget_exhausted {
+            p.completion = curr_time;
+            complete.push(p);
+            show_completion(config, &complete);
+        } else {
+            in_progress.push_back(p);
+        }
+        suspended.apply(&config.events, &mut next_event, curr_time, &mut in_progress);
+
+        // between two time slices is the one place everything rr knows is in these variables
+        if let Some(c) = checkpointing {
+            let stop = c.stop_at.is_some_and(|t| curr_time >= t);
+            if stop || curr_time >= next_checkpoint {
+                let state = RrState {
+                    time: curr_time,
+                    todo: todo.clone().into_vec(),
+                    queue: in_progress.clone(),
+                    suspended: suspended.clone(),
+                    next_event,
+                    complete: complete.clone(),
+                    segments: segments.clone(),
+                    queue_lengths: queue_lengths.clone(),
+                };
+                if let Err(e) = std::fs::write(&c.path, rr_checkpoint(&state, config)) {
+                    println!("Warning: could not write the checkpoint {}: {e}", c.path);
+                }
+                while next_checkpoint <= curr_time {
+                    next_checkpoint += c.every;
+                }
+            }
+            if stop {
+                println!("Checkpoint: stopped at t={curr_time}, saved to {}", c.path);
+                break;
+            }
+        }
+    }
+
+    // only non-empty if the loop was interrupted, everything still waiting or not arrived yet never finished
+    // (or something was suspended and never resumed)
+    let mut incomplete: Vec<Process> = in_progress.into_iter().collect();
+    incomplete.extend(todo.into_vec());
+    incomplete.extend(suspended.processes.iter().cloned());
+    record_ready_wait(&mut complete, &segments);
+    for p in complete.iter_mut() {
+        p.ready_wait -= suspended.after_first_run(p);
+    }
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// writes a process for a checkpoint as name=value pairs of every field, "-" for a None. Floats are written with {},
+// which gives the shortest text that parses back to exactly the same float, so nothing is lost
+// input: borrowed Process, output: String
+fn checkpoint_process(p: &Process) -> String {
+    fn or_dash<T: ToString>(value: Option<T>) -> String {
+        value.map_or("-".to_string(), |v| v.to_string())
     }
-    // sorting a vector using a comparator function. You might first notice here that we have an ampersand around b.arrival, wonder what it is
-    // and wonder why a.arrival does not have this either. Ampersand or & indicates that this function takes in the borrowed value of b.arrival
-    // There is a system of ownership within rust that only allows one pointer to a piece of data on the heap. But other functions such as partial_cmp()
-    // can take in the borrowed value of it to perform calculations without consuming the ownership of the original pointer.
-    // a.arrival also is being borrowed here, it is just that the Rust compilier will automatically add that during compile time. 
-    // unwrap_or here either returns the result from the partial comparison or returns an Ordering::Equal type to indicate that the 
-    // a.arrival and b.arrival are equal.
-    wkld.sort_by(|a, b| a.arrival.partial_cmp(&b.arrival).unwrap_or(Ordering::Equal));
-    wkld
+    let bursts: Vec<String> = p.bursts.iter().map(|b| b.to_string()).collect();
+    format!("pid={} arrival={} duration={} first_run={} completion={} remaining_time={} bursts={} priority={} preemptible={} \
+             ready_wait={} group={} deadline={} hard_deadline={} cpu_budget={} budget_exhausted={} yield_interval={} \
+             affinity={} affinity_violations={} period={} task={} start_level={} depends_on={} memory={} weight={}",
+        p.pid, p.arrival, p.duration, p.first_run, p.completion, p.remaining_time, bursts.join(","), p.priority,
+        p.preemptible, p.ready_wait, or_dash(p.group), or_dash(p.deadline), p.hard_deadline, or_dash(p.cpu_budget),
+        p.budget_exhausted, or_dash(p.yield_interval), or_dash(p.affinity), p.affinity_violations, or_dash(p.period),
+        or_dash(p.task), p.start_level, or_dash(p.depends_on), p.memory, p.weight)
 }
 
-// calculate average turnaround time (completion time - arrival time)
-// input: borrowed Vector of Processes, output: f32
-pub fn avg_turnaround(processes : &Vec<Process>) -> f32{
-    let n = processes.len() as f32; // note here the type declaration, .len() returns an integer, we convert it to f32 with as
-    let sum = processes.iter().fold(0.0, |acc, p| acc + p.completion - p.arrival); //similar to a reduce function
-    sum / n
+// reads a process written by checkpoint_process back
+// input: the text, output: Result, the Process or what is wrong with it
+fn parse_checkpoint_process(text: &str) -> Result<Process, String> {
+    fn value<T: FromStr>(name: &str, value: &str) -> Result<T, String> {
+        value.parse().map_err(|_| format!("{value} is not a valid {name}"))
+    }
+    fn optional<T: FromStr>(name: &str, v: &str) -> Result<Option<T>, String> {
+        if v == "-" { Ok(None) } else { value(name, v).map(Some) }
+    }
+    let mut p = Process::new(0.0, 0.0, 0.0, 0.0);
+    for field in text.split_whitespace() {
+        let (name, v) = field.split_once('=').ok_or(format!("{field} is not written as name=value"))?;
+        match name {
+            "pid" => p.pid = value(name, v)?,
+            "arrival" => p.arrival = value(name, v)?,
+            "duration" => p.duration = value(name, v)?,
+            "first_run" => p.first_run = value(name, v)?,
+            "completion" => p.completion = value(name, v)?,
+            "remaining_time" => p.remaining_time = value(name, v)?,
+            "bursts" => p.bursts = v.split(',').map(|b| value(name, b)).collect::<Result<_, _>>()?,
+            "priority" => p.priority = value(name, v)?,
+            "preemptible" => p.preemptible = value(name, v)?,
+            "ready_wait" => p.ready_wait = value(name, v)?,
+            "group" => p.group = optional(name, v)?,
+            "deadline" => p.deadline = optional(name, v)?,
+            "hard_deadline" => p.hard_deadline = value(name, v)?,
+            "cpu_budget" => p.cpu_budget = optional(name, v)?,
+            "budget_exhausted" => p.budget_exhausted = value(name, v)?,
+            "yield_interval" => p.yield_interval = optional(name, v)?,
+            "affinity" => p.affinity = optional(name, v)?,
+            "affinity_violations" => p.affinity_violations = value(name, v)?,
+            "period" => p.period = optional(name, v)?,
+            "task" => p.task = optional(name, v)?,
+            "start_level" => p.start_level = value(name, v)?,
+            "depends_on" => p.depends_on = optional(name, v)?,
+            "memory" => p.memory = value(name, v)?,
+            "weight" => p.weight = value(name, v)?,
+            _ => return Err(format!("unknown process field {name}")),
+        }
+    }
+    Ok(p)
 }
 
-// calculate average turnaround time (first run time - arrival time)
-// input: borrowed Vector of Processes, output: f32
-pub fn avg_response(processes : &Vec<Process>) -> f32 {
-    let n = processes.len() as f32;
-    let sum = processes.iter().fold(0.0, |acc, p| acc + p.first_run - p.arrival);
-    sum / n
+// writes rr's state and the settings that shape its schedule as text. Every list is a line with its name and length
+// followed by one line per item, like "queue 2" and then 2 processes
+// input: borrowed RrState, borrowed SimConfig, output: String
+fn rr_checkpoint(state: &RrState, config: &SimConfig) -> String {
+    let mut lines = vec![
+        "rr checkpoint".to_string(),
+        format!("config {} {} {} {}", config.quantum, config.speed, config.tick, config.dispatch_overhead),
+        format!("time {}", state.time),
+        format!("next_event {}", state.next_event),
+    ];
+    let mut section = |name: &str, items: Vec<String>| {
+        lines.push(format!("{name} {}", items.len()));
+        lines.extend(items);
+    };
+    section("events", config.events.iter().map(|e| format!("{} {} {}", e.time, e.pid, if e.suspend { "suspend" } else { "resume" })).collect());
+    section("todo", state.todo.iter().map(checkpoint_process).collect());
+    section("queue", state.queue.iter().map(checkpoint_process).collect());
+    section("suspended", state.suspended.processes.iter().map(checkpoint_process).collect());
+    section("before_arrival", state.suspended.before_arrival.iter().map(|pid| pid.to_string()).collect());
+    section("intervals", state.suspended.intervals.iter().map(|(pid, start, end)| format!("{pid} {start} {end}")).collect());
+    section("complete", state.complete.iter().map(checkpoint_process).collect());
+    section("segments", state.segments.iter().map(|(start, end, pid)| format!("{start} {end} {pid}")).collect());
+    section("queue_lengths", state.queue_lengths.iter().map(|(time, length)| format!("{time} {length}")).collect());
+    lines.join("\n") + "\n"
 }
 
-// prints processes
-// input: borrowed Vector of Processes, output: f32
-pub fn show_processes(processes: &Vec<Process>) {
-    let p_iter = processes.iter();
-    // note here, this function, println!, the exlamation mark indicates this is a macro. This macro is by default included in the prelude
-    // of the program. This is because Rust does not support variable arguments, so println has to be implemented as a macro to use format parameters
-    println!("Processes:");
-    for p in p_iter {
-        println!(
-            //format paramaters are illustrated here, very similar to how fstrings work in Python
-            // or std::format in C++
-            "\tarrival={}, duration={}, first_run={}, completion={}",
-            p.arrival, p.duration, p.first_run, p.completion
-        );
+// reads a checkpoint written by rr_checkpoint back, with config's other settings
+// input: the text, borrowed SimConfig, output: Result, (the state, the config to carry on with) or what is wrong and
+// on which line
+fn parse_rr_checkpoint(text: &str, config: &SimConfig) -> Result<(RrState, SimConfig), String> {
+    let mut lines = text.lines().enumerate();
+    // the rest of the next line after its name, which has to be name
+    let mut next = |name: &str| -> Result<(usize, String), String> {
+        let (i, line) = lines.next().ok_or(format!("ends before {name}"))?;
+        match line.strip_prefix(name) {
+            Some(rest) => Ok((i + 1, rest.trim().to_string())),
+            None => Err(format!("line {}: expected {name}", i + 1)),
+        }
+    };
+    // a number (or anything else FromStr) on line i
+    fn number<T: FromStr>(i: usize, text: &str) -> Result<T, String> {
+        text.parse().map_err(|_| format!("line {i}: {text} is not a number"))
+    }
+    next("rr checkpoint")?;
+    let (i, settings) = next("config")?;
+    let settings: Vec<f32> = settings.split_whitespace().map(|v| number(i, v)).collect::<Result<_, _>>()?;
+    if settings.len() != 4 {
+        return Err(format!("line {i}: config needs the quantum, speed, tick and dispatch overhead"));
     }
+    let (i, time) = next("time")?;
+    let time = number(i, &time)?;
+    let (i, next_event) = next("next_event")?;
+    let next_event = number(i, &next_event)?;
+    // the items of a section, each with its line number
+    let mut section = |name: &str| -> Result<Vec<(usize, String)>, String> {
+        let (i, count) = next(name)?;
+        (0..number::<usize>(i, &count)?).map(|_| next("")).collect()
+    };
+    let processes = |items: Vec<(usize, String)>| -> Result<Vec<Process>, String> {
+        items.iter().map(|(i, text)| parse_checkpoint_process(text).map_err(|e| format!("line {i}: {e}"))).collect()
+    };
+    // the columns of every item of a section, which has to have columns columns
+    let columns = |items: Vec<(usize, String)>, columns: usize| -> Result<Vec<(usize, Vec<String>)>, String> {
+        items.into_iter().map(|(i, text)| {
+            let values: Vec<String> = text.split_whitespace().map(|v| v.to_string()).collect();
+            if values.len() == columns { Ok((i, values)) } else { Err(format!("line {i}: needs {columns} columns")) }
+        }).collect()
+    };
+    let mut events = Vec::new();
+    for (i, e) in columns(section("events")?, 3)? {
+        let suspend = match e[2].as_str() {
+            "suspend" => true,
+            "resume" => false,
+            other => return Err(format!("line {i}: {other} is not suspend or resume")),
+        };
+        events.push(SuspendEvent { time: number(i, &e[0])?, pid: number(i, &e[1])?, suspend });
+    }
+    let todo = processes(section("todo")?)?;
+    let queue = processes(section("queue")?)?.into();
+    let suspended_processes = processes(section("suspended")?)?;
+    let before_arrival = section("before_arrival")?.iter().map(|(i, pid)| number(*i, pid)).collect::<Result<_, _>>()?;
+    let mut intervals = Vec::new();
+    for (i, interval) in columns(section("intervals")?, 3)? {
+        intervals.push((number(i, &interval[0])?, number(i, &interval[1])?, number(i, &interval[2])?));
+    }
+    let complete = processes(section("complete")?)?;
+    let mut segments = Vec::new();
+    for (i, segment) in columns(section("segments")?, 3)? {
+        segments.push((number(i, &segment[0])?, number(i, &segment[1])?, number(i, &segment[2])?));
+    }
+    let mut queue_lengths = Vec::new();
+    for (i, length) in columns(section("queue_lengths")?, 2)? {
+        queue_lengths.push((number(i, &length[0])?, number(i, &length[1])?));
+    }
+    let state = RrState {
+        time,
+        todo,
+        queue,
+        suspended: Suspended { processes: suspended_processes, before_arrival, intervals },
+        next_event,
+        complete,
+        segments,
+        queue_lengths,
+    };
+    let config = SimConfig { quantum: settings[0], speed: settings[1], tick: settings[2], dispatch_overhead: settings[3], events, ..config.clone() };
+    Ok((state, config))
 }
 
-// prints processes and metrics
-// input: borrowed Vector of Processes, output: None
-pub fn show_metrics(processes : &Vec<Process>){
-    let turn = avg_turnaround(processes);
-    let resp = avg_response(processes);
-    show_processes(processes);
-    println!("Average Turnaround Time: {}", turn);
-    println!("Average Response Time:   {}", resp);
-}
+// runs round robin with a separate queue for each of num_classes priority classes, a cross between rr and a multilevel
+// queue. A process's class is its priority, with anything below 0 in class 0 (the highest) and anything past the last
+// class in the last one, and it stays in that class. Every quantum the highest class that has a ready process gets the
+// CPU, and its processes take turns like in rr, so a lower class only runs once every class above it is empty. A
+// process of a higher class that arrives while a lower one is running takes the CPU at the next tick instead of
+// waiting for the slice to end. It goes a tick at a time like the other tick based algorithms, each doing speed work,
+// and like rr every slice is a dispatch that costs the dispatch overhead
+// input: borrowed Vector of Processes, borrowed SimConfig (classes, quantum, speed, dispatch_overhead), output:
+// ScheduleResult
+pub fn rr_classed(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let (num_classes, quantum) = (config.classes, config.quantum);
+    // first_run is -1 until the process first runs, like in rr
+    let mut todo: Vec<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    todo.sort_by(|a, b| by_arrival(b, a));
+    let mut todo = VecDeque::from(todo);
+    let n = todo.len();
+    let bottom = num_classes - 1;
+    let class = |p: &Process| (p.priority.max(0) as usize).min(bottom);
+    let mut queues: Vec<VecDeque<Process>> = vec![VecDeque::new(); num_classes];
+    let mut queue_lengths = Vec::new();
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
 
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
 
-//----------ALGORITHMS-----------
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            let p = todo.pop_front().unwrap();
+            queues[class(&p)].push_back(p);
+        }
+        // nothing is ready in any class, skip ahead to the next arrival
+        let level = match queues.iter().position(|q| !q.is_empty()) {
+            Some(level) => level,
+            None => {
+                curr_time = todo.front().unwrap().arrival;
+                continue;
+            }
+        };
 
+        let mut p = queues[level].pop_front().unwrap();
+        curr_time += config.dispatch_overhead;
+        let mut slice = 0.0;
+        loop {
+            // + 1 for p, which is on the CPU
+            queue_lengths.push((curr_time, queues.iter().map(|q| q.len()).sum::<usize>() + 1));
+            if p.first_run == -1.0 {
+                p.first_run = curr_time;
+            }
+            run_tick(&mut p, config.speed);
+            add_segment(&mut segments, curr_time, curr_time + 1.0, p.pid);
+            curr_time += 1.0;
+            slice += 1.0;
 
-// runs FIFO algorithm
-// input: borrowed Vector of Processes, output: Vector of Processes
-pub fn fifo(workload: &Vec<Process>) -> Vec<Process> {
-    // Note here, that all these variables are declared with mut or mutable, by default values are not mutable. This ensures saftey as well
-    // by preventing unecessary changes to references.
-    let mut complete : Vec<Process> = Vec::new();
-    let wkld_iter = workload.iter();
-    let mut curr_time = workload.get(0).unwrap().arrival;
+            while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+                let q = todo.pop_front().unwrap();
+                queues[class(&q)].push_back(q);
+            }
+            let higher_ready = queues[..level].iter().any(|q| !q.is_empty());
+            if p.remaining_time <= 0.0 || slice >= quantum || higher_ready {
+                break;
+            }
+        }
 
-    for process in wkld_iter{
-        let p = Process::new(process.arrival, process.duration, curr_time, curr_time+process.duration);
-        curr_time += process.duration;
-        complete.push(p);
+        if p.remaining_time <= 0.0 {
+            p.completion = curr_time;
+            complete.push(p);
+        } else {
+            queues[level].push_back(p);
+        }
     }
-    // note here, we give up ownership of complete to whatever reference points to this function call
-    // also note, because this is the last statement in the function, this is returned without an explicit call to return
-    // this is the idiomatic way to return, calling return if the thing you are returning is the last statement works but is 
-    // considered to be not idiomatic. Notice here that there is no semicolon either, if you added a semicolon, this function would
-    // instead return a None type. 
-    complete
+
+    // only non-empty if the loop was interrupted
+    let mut incomplete: Vec<Process> = queues.into_iter().flatten().chain(todo).collect();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
 }
 
-// runs SJF algorithm
-// input: borrowed Vector of Processes, output: Vector of Processes
-pub fn sjf(workload:  &Vec<Process>) -> Vec<Process> {
-    let mut wkld = VecDeque::from(workload.clone());
+// runs rr on num_cpus CPUs sharing one ready queue. Whenever a CPU is free it takes the first process in the queue that
+// may run there, a process without an affinity (or with one past the last CPU) may run anywhere, one with an affinity
+// only on that CPU, unless it has waited in the queue for affinity_wait since it last joined it. Then it takes any free
+// CPU, and that slice counts as an affinity violation. So a process waits a while for its own CPU, keeping its cache
+// warm, even with another CPU idle, and only moves when the wait has gone on too long. Like rr, a slice is quantum ticks
+// (or until the process completes) and processes that arrive during it join the queue ahead of the one it ends for.
+// CPUs that free up together take from the queue in CPU order. The CPUs go a step at a time together, each doing speed
+// work a tick, and like rr every slice is a dispatch, the CPU spends the dispatch overhead before the slice starts
+// input: borrowed Vector of Processes, borrowed SimConfig (cpus, quantum, affinity_wait, speed, tick,
+// dispatch_overhead), output: ScheduleResult
+pub fn rr_multi(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let num_cpus = config.cpus;
+    // first_run is -1 until the process first runs, like in rr
+    let mut todo: Vec<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    todo.sort_by(|a, b| by_arrival(b, a));
+    let mut todo = VecDeque::from(todo);
+    let n = todo.len();
+    // the ready queue, every process with the time it joined it
+    let mut queue: VecDeque<(Process, f32)> = VecDeque::new();
+    // what each CPU is running, with the time its slice started, which is after the dispatch overhead
+    let mut cpus: Vec<Option<(Process, f32)>> = vec![None; num_cpus];
+    let mut queue_lengths = Vec::new();
     let mut complete : Vec<Process> = Vec::new();
-    let mut curr_time = wkld.get(0).unwrap().arrival;
-    
-    //Here I'm calling from the binary_heap_plus crate to use the BinaryHeap. This allows me to pass in a custom comparator.
-    // Note as well the vec![] macro, this creates a vector from an array.
-    let mut duration = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![], 
-        |p1: &Process, p2 :&Process| p2.duration.partial_cmp(&p1.duration).unwrap());
+    let mut segments = Vec::new();
+    let mut segment_cpus = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
 
-    duration.push(wkld.pop_front().unwrap());
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
 
-    while !duration.is_empty() {
-        let mut p = duration.pop().unwrap();
-        p.first_run = curr_time;
-        curr_time += p.duration;
-        p.completion = curr_time;
-        complete.push(p);
-        while !wkld.is_empty() && curr_time >= wkld.get(0).unwrap().arrival {
-            let p2 = wkld.pop_front().unwrap();
-            duration.push(p2);
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            queue.push_back((todo.pop_front().unwrap(), curr_time));
+        }
+        for (cpu, running) in cpus.iter_mut().enumerate() {
+            if running.is_some() {
+                continue;
+            }
+            let fits = |(p, joined): &(Process, f32)| {
+                p.affinity.is_none_or(|a| a == cpu || a >= num_cpus) || curr_time - joined >= config.affinity_wait
+            };
+            if let Some(i) = queue.iter().position(fits) {
+                let (mut p, _) = queue.remove(i).unwrap();
+                if p.affinity.is_some_and(|a| a != cpu && a < num_cpus) {
+                    p.affinity_violations += 1;
+                }
+                let start = curr_time + config.dispatch_overhead;
+                if p.first_run == -1.0 {
+                    p.first_run = start;
+                }
+                *running = Some((p, start));
+            }
+        }
+        // every CPU is idle and nothing is ready, skip ahead to the next arrival
+        if cpus.iter().all(|c| c.is_none()) {
+            curr_time = todo.front().unwrap().arrival;
+            continue;
+        }
+        queue_lengths.push((curr_time, queue.len() + cpus.iter().flatten().count()));
+
+        // the step ends where the first CPU needs looking at again, a CPU still paying the dispatch overhead when its
+        // slice starts, one that is running at the end of its step_length
+        let next_arrival = todo.front().map(|q| q.arrival);
+        let step = cpus.iter().flatten().fold(f32::INFINITY, |step, (p, start)| match curr_time < *start {
+            true => step.min(start - curr_time),
+            false => step.min(step_length(config, p, curr_time, next_arrival)),
+        });
+        for (p, start) in cpus.iter_mut().flatten() {
+            if curr_time >= *start {
+                run_tick(p, config.speed * step);
+            }
+        }
+        curr_time += step;
+        // a process whose slice ends now goes back in the queue before the ones arriving now join it, like in rr
+        for (on, cpu) in cpus.iter_mut().enumerate() {
+            let ended = cpu.as_ref().is_some_and(|(p, start)| {
+                curr_time >= *start && (p.remaining_time <= 0.0 || curr_time - start >= config.quantum)
+            });
+            if !ended {
+                continue;
+            }
+            let (mut p, start) = cpu.take().unwrap();
+            segments.push((start, curr_time, p.pid));
+            segment_cpus.push(on);
+            if p.remaining_time <= 0.0 {
+                p.completion = curr_time;
+                complete.push(p);
+            } else {
+                queue.push_back((p, curr_time));
+            }
         }
     }
 
-    complete
+    // only non-empty if the loop was interrupted, the slices still running then end where it stopped (a CPU still
+    // paying the dispatch overhead hadn't started its slice)
+    let mut incomplete: Vec<Process> = Vec::new();
+    for (on, (p, start)) in cpus.into_iter().enumerate().filter_map(|(on, c)| c.map(|c| (on, c))) {
+        if curr_time > start {
+            segments.push((start, curr_time, p.pid));
+            segment_cpus.push(on);
+        }
+        incomplete.push(p);
+    }
+    incomplete.extend(queue.into_iter().map(|(p, _)| p).chain(todo));
+    // the segments went in as the slices ended, sort them (and their CPUs with them) by start
+    let mut order: Vec<((f32, f32, usize), usize)> = segments.into_iter().zip(segment_cpus).collect();
+    order.sort_by(|a, b| OrderedF32(a.0.0).cmp(&OrderedF32(b.0.0)).then(a.0.2.cmp(&b.0.2)));
+    let (segments, segment_cpus): (Vec<_>, Vec<_>) = order.into_iter().unzip();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus }
 }
 
-// runs STCF algorithm
-// input: borrowed Vector of Processes, output: Vector of Processes
-pub fn stcf(workload: &Vec<Process>) -> Vec<Process> {
-    let wkld = workload.clone();
+// runs two level scheduling. Only max_in_memory processes are in memory at a time (the degree of multiprogramming),
+// the rest have arrived but are still swapped out. The long term scheduler brings swapped out processes into memory
+// in arrival order whenever there is room, which is only when an in memory process completes, and the short term
+// scheduler runs round robin with a 1 tick time slice over the processes in memory. Like rr, every slice does speed
+// work and is a dispatch that costs the dispatch overhead
+// input: borrowed Vector of Processes, borrowed SimConfig (max_in_memory, speed, dispatch_overhead), output: ScheduleResult
+pub fn two_level(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let max_in_memory = config.max_in_memory;
+    // first_run is -1 until the process first runs, like in rr
+    let mut todo: VecDeque<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    let n = todo.len();
+    let mut swapped: VecDeque<Process> = VecDeque::new();
+    let mut memory: VecDeque<Process> = VecDeque::new();
+    let mut queue_lengths = Vec::new();
     let mut complete : Vec<Process> = Vec::new();
-    let mut todo = binary_heap_plus::BinaryHeap::from_vec_cmp(wkld.to_vec(), 
-        |p1: &Process, p2 :&Process| p2.arrival.partial_cmp(&p1.arrival).unwrap());
-    let mut in_progress_dur = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![], 
-        |p1: &Process, p2 :&Process| p2.duration.partial_cmp(&p1.duration).unwrap());
+    let mut segments = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
 
-    let mut curr_time = todo.peek().unwrap().arrival;
-    let mut init = todo.peek().unwrap().clone();
-    init.first_run = -1.0;
-    in_progress_dur.push(init);
-    todo.pop();
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
 
-    while !in_progress_dur.is_empty() {
-        while !todo.is_empty() && curr_time == todo.peek().unwrap().arrival {
-            let p = todo.pop().unwrap();
-            let mut p_clone = p.clone();
-            p_clone.first_run = -1.0;
-            in_progress_dur.push(p_clone);
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            swapped.push_back(todo.pop_front().unwrap());
+        }
+        // long term scheduler, swap in the earliest arrivals while there is room
+        while memory.len() < max_in_memory && !swapped.is_empty() {
+            memory.push_back(swapped.pop_front().unwrap());
         }
 
-        let mut p = in_progress_dur.pop().unwrap();
+        // only the processes in memory are ready, swapped out ones can't run
+        if !memory.is_empty() {
+            queue_lengths.push((curr_time, memory.len()));
+        }
+        // nothing is in memory or waiting to be, skip ahead to the next arrival
+        let mut p = match memory.pop_front() {
+            Some(p) => p,
+            None => {
+                curr_time = todo.front().unwrap().arrival;
+                continue;
+            }
+        };
+        curr_time += config.dispatch_overhead;
         if p.first_run == -1.0 {
             p.first_run = curr_time;
         }
-        p.duration -= 1.0;
+        run_tick(&mut p, config.speed);
+        add_segment(&mut segments, curr_time, curr_time + 1.0, p.pid);
         curr_time += 1.0;
 
-        if p.duration == 0.0 {
+        if p.remaining_time <= 0.0 {
             p.completion = curr_time;
             complete.push(p);
         } else {
-            in_progress_dur.push(p);
+            memory.push_back(p);
         }
     }
 
-    complete
+    // only non-empty if the loop was interrupted
+    let mut incomplete: Vec<Process> = memory.into_iter().chain(swapped).chain(todo).collect();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
 }
 
-// runs RR algorithm
-// input: borrowed Vector of Processes, output: Vector of Processes
-pub fn rr(workload: &Vec<Process>) -> Vec<Process> {
-    let wkld = workload.clone();
+// runs Smith's rule, whenever the CPU is free the ready process with the highest weight / duration runs until it
+// completes (ties go to the earlier arrival, then the lower pid). When every process is there from the start this is
+// the order with the least total weighted completion time: if a process with a lower ratio ran right before one with a
+// higher ratio, swapping them saves more weighted time on the one moved up than it costs the one moved back. With every
+// weight 1 it is sjf (except that sjf starts the first line right away)
+// input: borrowed Vector of Processes, output: ScheduleResult
+pub fn smiths_rule(workload: &Vec<Process>) -> ScheduleResult {
+    let mut todo: Vec<Process> = workload.iter().map(Process::reset).collect();
+    todo.sort_by(|a, b| by_arrival(b, a));
+    let mut todo = VecDeque::from(todo);
+    let n = todo.len();
+    let mut ready: Vec<Process> = Vec::new();
+    let mut queue_lengths = Vec::new();
     let mut complete : Vec<Process> = Vec::new();
-    let mut todo = binary_heap_plus::BinaryHeap::from_vec_cmp(wkld.to_vec(), 
-        |p1: &Process, p2 :&Process| p2.arrival.partial_cmp(&p1.arrival).unwrap());
-    let mut in_progress: VecDeque<Process> = VecDeque::new();
-    let mut curr_time = todo.peek().unwrap().arrival;
-    let mut init = todo.peek().unwrap().clone();
-    init.first_run = -1.0;
-    in_progress.push_back(init);
-    todo.pop();
+    let mut segments = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
 
-    // Note, pop_front() returns an Option enum, which can either be Some or None. None is similar to null while avoiding having null
-    // this check that that pop_front() pops a Some type and not a None type
-    while let Some(mut p) = in_progress.pop_front() {
-        while !todo.is_empty() && curr_time == todo.peek().unwrap().arrival {
-            let p = todo.pop().unwrap();
-            let p = Process {
-                first_run: -1.0,
-                ..p // Note here, this essentially fills in the rest of the fields with the fields from the original p
-            };
-            in_progress.push_back(p);
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
         }
 
-        if p.first_run == -1.0 {
-            p.first_run = curr_time;
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            ready.push(todo.pop_front().unwrap());
         }
-        p.remaining_time -= 1.0;
-        curr_time += 1.0;
+        // nothing is ready, skip ahead to the next arrival
+        if ready.is_empty() {
+            curr_time = todo.front().unwrap().arrival;
+            continue;
+        }
+        queue_lengths.push((curr_time, ready.len()));
 
-        if p.remaining_time == 0.0 {
-            p.completion = curr_time;
-            complete.push(p);
-        } else {
-            in_progress.push_back(p);
+        // max_by keeps the last of equal maximums, so equal ratios go by by_arrival, which is written that way already
+        let ratio = |p: &Process| p.weight / p.duration;
+        let next = (0..ready.len()).max_by(|&i, &j| {
+            OrderedF32(ratio(&ready[i])).cmp(&OrderedF32(ratio(&ready[j]))).then(by_arrival(&ready[i], &ready[j]))
+        }).unwrap();
+        let mut p = ready.remove(next);
+        p.first_run = curr_time;
+        curr_time += p.duration;
+        p.completion = curr_time;
+        p.remaining_time = 0.0;
+        add_segment(&mut segments, p.first_run, p.completion, p.pid);
+        complete.push(p);
+    }
+
+    // only non-empty if the loop was interrupted
+    let mut incomplete: Vec<Process> = ready.into_iter().chain(todo).collect();
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// calculate the total weighted completion time, every process's weight times its completion time, counted from the
+// earliest arrival. This is what smiths_rule makes as small as it can be
+// input: borrowed Vector of Processes, output: f32
+pub fn weighted_completion(processes: &Vec<Process>) -> f32 {
+    let start = processes.iter().fold(f32::MAX, |acc, p| acc.min(p.arrival));
+    processes.iter().map(|p| p.weight * (p.completion - start)).sum()
+}
+
+// runs memory constrained scheduling, sjf where a process also needs its memory to run. A process that has arrived is
+// only admitted to the ready set if its memory fits in what is left of total_memory, and it holds that memory until it
+// completes. Whenever the CPU is free the admitted process with the shortest duration runs until it completes, so a
+// short process that needs a lot of memory can wait behind longer ones that were admitted first. Waiting processes are
+// admitted in arrival order, passing over any that don't fit yet, and one that needs more than total_memory on its own
+// can never run and is reported as incomplete
+// input: borrowed Vector of Processes, total memory, output: ScheduleResult
+pub fn memory_constrained(workload: &Vec<Process>, total_memory: f32) -> ScheduleResult {
+    let (mut incomplete, fits): (Vec<Process>, Vec<Process>) = workload.iter().map(Process::reset).partition(|p| p.memory > total_memory);
+    let mut todo: VecDeque<Process> = fits.into_iter().collect();
+    let n = todo.len();
+    // arrived but not admitted yet
+    let mut waiting: Vec<Process> = Vec::new();
+    let mut ready: Vec<Process> = Vec::new();
+    let mut free = total_memory;
+    let mut queue_lengths = Vec::new();
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut curr_time = todo.front().map_or(0.0, |p| p.arrival);
+
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            waiting.push(todo.pop_front().unwrap());
+        }
+        let mut i = 0;
+        while i < waiting.len() {
+            if waiting[i].memory <= free {
+                free -= waiting[i].memory;
+                ready.push(waiting.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+
+        // nothing runs while a process is waiting, so with nothing ready all the memory is free and nothing is
+        // waiting either, skip ahead to the next arrival
+        if ready.is_empty() {
+            curr_time = todo.front().unwrap().arrival;
+            continue;
         }
+        queue_lengths.push((curr_time, ready.len()));
+
+        // by_arrival is written for the max heaps, so its arguments are swapped for min_by
+        let next = (0..ready.len()).min_by(|&i, &j| {
+            OrderedF32(ready[i].duration).cmp(&OrderedF32(ready[j].duration)).then(by_arrival(&ready[j], &ready[i]))
+        }).unwrap();
+        let mut p = ready.remove(next);
+        p.first_run = curr_time;
+        curr_time += p.duration;
+        p.completion = curr_time;
+        p.remaining_time = 0.0;
+        add_segment(&mut segments, p.first_run, p.completion, p.pid);
+        free += p.memory;
+        complete.push(p);
     }
 
-    complete
+    // the processes that needed too much memory, and if the loop was interrupted everything else that didn't finish
+    incomplete.extend(ready.into_iter().chain(waiting).chain(todo));
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
 }
 
 // runs MLFQ algorithm
-// input: borrowed Vector of Processes, output: Vector of Processes
-pub fn mlfq(workload: &Vec<Process>) -> Vec<Process> {
-    let wkld = workload.clone();
-    let mut todo = binary_heap_plus::BinaryHeap::from_vec_cmp(wkld.to_vec(), 
-        |p1: &Process, p2 :&Process| p2.arrival.partial_cmp(&p1.arrival).unwrap());
+// input: borrowed Vector of Processes, borrowed SimConfig, output: ScheduleResult
+pub fn mlfq(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let wkld: Vec<Process> = workload.iter().map(Process::reset).collect();
+    let mut todo = binary_heap_plus::BinaryHeap::from_vec_cmp(wkld.to_vec(), by_arrival);
 
     // creates size 4 array of VectorDeques, four levels in the MLFQ
-    let mut mlfq : [VecDeque<Process>; 4]= [VecDeque::new(), VecDeque::new(), VecDeque::new(),VecDeque::new()];
+    // each entry is a process along with how much CPU time it has used at its current level, for the demotion policy
+    let mut mlfq : [VecDeque<(Process, f32)>; 4]= [VecDeque::new(), VecDeque::new(), VecDeque::new(),VecDeque::new()];
+    let mut queue_lengths = Vec::new();
     let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
     let mut curr_time = todo.peek().unwrap().arrival;
     let mut init = todo.pop().unwrap().clone();
     let mut counter = 1;
     init.first_run = -1.0;
-    mlfq[0].push_back(init);
-    let mut curr_queue = 0;
+    // a start_level past the last level means the last level
+    let bottom = mlfq.len() - 1;
+    mlfq[init.start_level.min(bottom)].push_back((init, 0.0));
 
     // while we still have processes left to finish
     while complete.len() != wkld.len() {
+        // ctrl-c was pressed, stop and return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
 
-        // boosting mechanism, go through all levels and elevate to first level
-        if counter % BOOSTTIME == 0{
+        // boosting mechanism, go through all levels and elevate to first level (or the level above with OneLevel)
+        // going from the top down means a level is already emptied into the one above before the level below it
+        // moves in, so nothing moves twice
+        // BOOSTTIME is in ticks of time, counter in steps, which are shorter than a tick with a --tick under 1
+        if counter % (BOOSTTIME as f32 / config.tick).round().max(1.0) as i32 == 0{
             let mut j = 1;
             while j < mlfq.len(){
+                let to = match config.boost {
+                    BoostPolicy::ToTop => 0,
+                    BoostPolicy::OneLevel => j - 1,
+                };
                 while !mlfq[j].is_empty(){
-                    let p = mlfq[j].pop_front().unwrap();
-                    mlfq[0].push_back(p);
+                    let (p, _) = mlfq[j].pop_front().unwrap();
+                    mlfq[to].push_back((p, 0.0));
                 }
                 j += 1;
             }
-            curr_queue=0;
         }
 
         // mechanism to read in processes if the current time matches the arrival time of that process
-        while !todo.is_empty() && curr_time == todo.peek().unwrap().arrival {
+        while !todo.is_empty() && curr_time >= todo.peek().unwrap().arrival {
             let p_add = todo.pop().unwrap();
             let p_add = Process {
                 first_run: -1.0,
                 ..p_add
             };
-            mlfq[0].push_front(p_add);
-            curr_queue = 0;
+            mlfq[p_add.start_level.min(bottom)].push_front((p_add, 0.0));
         }
         
         // printing functionality
-        if MLFQPRINTING {
-            println!("{counter}");
-            let mut pr = 0;
-            for vd in mlfq.iter(){
-                println!("time: {counter} MLFQ Level {pr}: {:?}",vd);
-                pr += 1;
+        if config.mlfq_printing {
+            match config.mlfq_format {
+                MlfqFormat::Compact => {
+                    let lengths: Vec<String> = mlfq.iter().map(|vd| vd.len().to_string()).collect();
+                    println!("t={curr_time} [{}]", lengths.join(","));
+                }
+                MlfqFormat::Verbose => {
+                    println!("{counter}");
+                    for (pr, vd) in mlfq.iter().enumerate(){
+                        let procs: Vec<&Process> = vd.iter().map(|(p, _)| p).collect();
+                        println!("time: {counter} MLFQ Level {pr}: {:?}",procs);
+                    }
+                }
             }
         }
 
+        // mechanism to pick the current queue, always the highest level that has something in it
+        // if every level is empty the CPU is idle until the next arrival, so skip ahead to it
+        let curr_queue = match mlfq.iter().position(|q| !q.is_empty()) {
+            Some(level) => level,
+            None => {
+                curr_time = todo.peek().unwrap().arrival;
+                continue;
+            }
+        };
+
+        queue_lengths.push((curr_time, mlfq.iter().map(|q| q.len()).sum()));
+
         // Putting a process onto the cpu for a time quantum of 1 (maybe think of not as a second or measure of time but as a CPU cycle)
-        let mut p = mlfq[curr_queue].pop_front().unwrap();
+        let (mut p, mut used) = mlfq[curr_queue].pop_front().unwrap();
+        // every tick is a new time slice, so a new dispatch
+        curr_time += config.dispatch_overhead;
+        if p.first_run == -1.0 {
+            p.first_run = curr_time;
+        }
+        let step = step_length(config, &p, curr_time, None);
+        run_tick(&mut p, config.speed * step);
+        used += step;
+        add_segment(&mut segments, curr_time, curr_time + step, p.pid);
+        curr_time += step;
+
+        // mechanism to decide where put a process after taking off CPU, if its done put it in complete
+        // if not, the demotion policy decides whether it has used up its time at this level. If it has it goes to the
+        // next level down (unless we are already at the last level, dont go further), otherwise to the back of its level
+        if p.remaining_time <= 0.0 {
+            p.completion = curr_time;
+            complete.push(p);
+            show_completion(config, &complete);
+        } else if config.demotion.demotes(used) {
+            let z = if curr_queue+1 >= mlfq.len() {curr_queue} else {curr_queue+1};
+            mlfq[z].push_back((p, 0.0));
+        } else {
+            mlfq[curr_queue].push_back((p, used));
+        }
+        counter += 1;
+    }
+
+    // only non-empty if the loop was interrupted, everything still in a queue or not arrived yet never finished
+    let mut incomplete: Vec<Process> = mlfq.into_iter().flatten().map(|(p, _)| p).collect();
+    incomplete.extend(todo.into_vec());
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// runs CFS (the completely fair scheduler from Linux) style scheduling. Every process has a virtual runtime, how much
+// CPU time it has had, and the process with the least vruntime is the one that runs. Once on the CPU a process runs
+// for at least min_granularity ticks before cfs checks again whether something else has less vruntime, so it doesn't
+// switch every tick. A process that arrives starts with the smallest vruntime of the ready set, so it doesn't get
+// to run for ages to catch up with processes that have been running since long before it arrived, and an arrival
+// makes cfs check right away. Every process has the same weight here, so they all get an equal share of the CPU.
+// vruntime is time on the CPU, so at a speed under 1 everything takes more of it. Every time cfs picks a process,
+// even the one that was running, is a dispatch that costs the dispatch overhead
+// input: borrowed Vector of Processes, borrowed SimConfig (min_granularity, speed, dispatch_overhead), output: ScheduleResult
+pub fn cfs(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let min_granularity = config.min_granularity;
+    // first_run is -1 until the process first runs, like in rr
+    let mut todo: VecDeque<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    let n = todo.len();
+    // the heap holds (vruntime, process) tuples with the smallest vruntime on top, equal vruntimes go in pid order so
+    // the schedule is always the same. It plays the part of the red-black tree in Linux
+    let mut ready = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![],
+        |a: &(f32, Process), b: &(f32, Process)| OrderedF32(b.0).cmp(&OrderedF32(a.0)).then(b.1.pid.cmp(&a.1.pid)));
+    // the process on the CPU, its vruntime, and how long it has run since it was put on
+    let mut running: Option<(f32, Process, f32)> = None;
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut queue_lengths = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
+
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        let mut arrived = false;
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            let min_vruntime = ready.peek().map(|r| r.0).into_iter()
+                .chain(running.as_ref().map(|r| r.0))
+                .fold(f32::MAX, f32::min);
+            let vruntime = if min_vruntime == f32::MAX { 0.0 } else { min_vruntime };
+            ready.push((vruntime, todo.pop_front().unwrap()));
+            arrived = true;
+        }
+
+        // put the running process back and pick again once it has had its minimum time, or when something arrived
+        if running.as_ref().is_some_and(|r| r.2 >= min_granularity) || arrived {
+            if let Some((vruntime, p, _)) = running.take() {
+                ready.push((vruntime, p));
+            }
+        }
+        if running.is_none() {
+            match ready.pop() {
+                Some((vruntime, p)) => {
+                    curr_time += config.dispatch_overhead;
+                    running = Some((vruntime, p, 0.0));
+                }
+                None => {
+                    // nothing is ready, skip ahead to the next arrival
+                    curr_time = todo.front().unwrap().arrival;
+                    continue;
+                }
+            }
+        }
+        queue_lengths.push((curr_time, ready.len() + 1));
+
+        let (mut vruntime, mut p, mut ran) = running.take().unwrap();
         if p.first_run == -1.0 {
             p.first_run = curr_time;
         }
-        p.remaining_time -= 1.0;
+        run_tick(&mut p, config.speed);
+        vruntime += 1.0;
+        ran += 1.0;
+        add_segment(&mut segments, curr_time, curr_time + 1.0, p.pid);
         curr_time += 1.0;
 
-        // mechanism to change the current queue pointer
-        let mut changed = false;
-        if mlfq[curr_queue].is_empty() && curr_queue+1 < mlfq.len(){
-            curr_queue = (curr_queue+1)%mlfq.len();
-            changed = true;
+        if p.remaining_time <= 0.0 {
+            p.completion = curr_time;
+            complete.push(p);
+        } else {
+            running = Some((vruntime, p, ran));
         }
+    }
 
-        // mechanism to decide where put a process after taking off CPU, if its done put it in complete
-        // if not, if we have already changed our current queue pointer, put process on the current queue pointer
-        // avoids skipping a level
-        // also if we are currently at the last level, dont go further
-        // otherwise, put on the next level
-        if p.remaining_time == 0.0 {
+    // only non-empty if the loop was interrupted
+    let mut incomplete: Vec<Process> = ready.into_vec().into_iter().map(|(_, p)| p).chain(running.map(|r| r.1)).chain(todo).collect();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// runs EDF (earliest deadline first), every tick the ready process whose deadline is soonest runs, so a process that
+// arrives with an earlier deadline preempts the running one. Processes without a deadline come after every process
+// with one, and equal deadlines go by arrival and then pid so the schedule is always the same. It is meant for
+// periodic workloads (see expand_periodic), where EDF meets every deadline as long as the CPU isn't over 100% used.
+// With on_miss, every tick it checks whether a ready process has more time left than there is until its deadline, and
+// stops (the rest end up in incomplete) or drops that process (it ends up in incomplete) instead of running it anyway.
+// Every tick does speed work, and like stcf a process only pays the dispatch overhead when it takes the CPU over
+// input: borrowed Vector of Processes, borrowed SimConfig (on_miss, speed, dispatch_overhead), output: ScheduleResult
+pub fn edf(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let on_miss = config.on_miss;
+    // first_run is -1 until the process first runs, like in rr
+    let mut todo: VecDeque<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    let n = todo.len();
+    let mut ready = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![], |p1: &Process, p2: &Process| {
+        let d1 = p1.deadline.unwrap_or(f32::MAX);
+        let d2 = p2.deadline.unwrap_or(f32::MAX);
+        OrderedF32(d2).cmp(&OrderedF32(d1)).then(by_arrival(p1, p2))
+    });
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut queue_lengths = Vec::new();
+    // the processes on_miss dropped
+    let mut dropped: Vec<Process> = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
+
+    while complete.len() + dropped.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            ready.push(todo.pop_front().unwrap());
+        }
+
+        if let Some(action) = on_miss {
+            let late = |p: &Process| p.deadline.is_some_and(|deadline| curr_time + p.remaining_time / config.speed > deadline);
+            // sorted so the message always names the same process, the soonest deadline first
+            let mut misses: Vec<Process> = ready.iter().filter(|p| late(p)).cloned().collect();
+            misses.sort_by(|a, b| a.deadline.map(OrderedF32).cmp(&b.deadline.map(OrderedF32)).then(a.pid.cmp(&b.pid)));
+            if action == MissAction::Stop {
+                if let Some(p) = misses.first() {
+                    println!("Deadline Miss: pid {} can't complete by its deadline {} anymore, {} left at t={curr_time}, stopping", p.pid, p.deadline.unwrap(), p.remaining_time);
+                    break;
+                }
+            } else if !misses.is_empty() {
+                for p in &misses {
+                    println!("Deadline Miss: pid {} can't complete by its deadline {} anymore, {} left at t={curr_time}, dropping it", p.pid, p.deadline.unwrap(), p.remaining_time);
+                }
+                let kept: Vec<Process> = ready.drain().filter(|p| !late(p)).collect();
+                for p in kept {
+                    ready.push(p);
+                }
+                dropped.extend(misses);
+            }
+        }
+
+        // nothing is ready, skip ahead to the next arrival
+        if ready.is_empty() {
+            // everything left was dropped
+            if todo.is_empty() {
+                break;
+            }
+            curr_time = todo.front().unwrap().arrival;
+            continue;
+        }
+        queue_lengths.push((curr_time, ready.len()));
+
+        // the running process goes back in the heap every tick, so it is only still running next tick if nothing
+        // with an earlier deadline arrived
+        let mut p = ready.pop().unwrap();
+        if is_new_dispatch(&segments, p.pid, curr_time) {
+            curr_time += config.dispatch_overhead;
+        }
+        if p.first_run == -1.0 {
+            p.first_run = curr_time;
+        }
+        run_tick(&mut p, config.speed);
+        add_segment(&mut segments, curr_time, curr_time + 1.0, p.pid);
+        curr_time += 1.0;
+
+        if p.remaining_time <= 0.0 {
             p.completion = curr_time;
             complete.push(p);
         } else {
-            let z = if changed  || curr_queue+1 >= mlfq.len() {curr_queue} else {curr_queue+1};
-            mlfq[z].push_back(p);
+            ready.push(p);
+        }
+    }
+
+    // only non-empty if the loop was interrupted, stopped by on_miss or on_miss dropped something
+    let mut incomplete: Vec<Process> = ready.into_vec().into_iter().chain(todo).chain(dropped).collect();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// runs a hybrid of SJF and RR that switches between them every window ticks, counting windows from time 0: SJF for the
+// first window, RR for the next, SJF again after that and so on. In a SJF window the shortest ready process runs until
+// it completes, in a RR window every ready process gets one tick in turn (rr with a quantum of 1). The ready set carries
+// across a switch, so a process SJF was running when its window ends goes to the back of the RR queue, and whatever RR
+// didn't finish is there for SJF to pick from. Every tick does speed work, and every time a process is picked from the
+// ready set (every tick in a RR window, only once per process in a SJF window) is a dispatch with its overhead
+// input: borrowed Vector of Processes, borrowed SimConfig (window, speed, dispatch_overhead), output: ScheduleResult
+pub fn hybrid(workload: &Vec<Process>, config: &SimConfig) -> ScheduleResult {
+    let window = config.window;
+    // first_run is -1 until the process first runs, like in rr
+    let mut todo: VecDeque<Process> = workload.iter().map(|p| Process { first_run: -1.0, ..p.reset() }).collect();
+    let n = todo.len();
+    // the ready set in RR's order, SJF takes the shortest out of it wherever it is
+    let mut ready: VecDeque<Process> = VecDeque::new();
+    // the process SJF picked, it keeps the CPU until it completes or the window ends
+    let mut running: Option<Process> = None;
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut queue_lengths = Vec::new();
+    let mut curr_time = todo.front().unwrap().arrival;
+    let sjf_window = |time: f32| ((time / window).floor() as u64).is_multiple_of(2);
+
+    while complete.len() != n {
+        // ctrl-c was pressed, return what has finished
+        if INTERRUPTED.load(AtomicOrdering::Relaxed) {
+            break;
+        }
+
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            ready.push_back(todo.pop_front().unwrap());
+        }
+
+        // nothing is ready, skip ahead to the next arrival
+        if ready.is_empty() && running.is_none() {
+            curr_time = todo.front().unwrap().arrival;
+            continue;
+        }
+        queue_lengths.push((curr_time, ready.len() + running.iter().len()));
+
+        // a process SJF is running stays on the CPU, anything else is put on it
+        let dispatched = running.is_none();
+        let mut p = match running.take() {
+            Some(p) => p,
+            None if sjf_window(curr_time) => {
+                let shortest = ready.iter().enumerate()
+                    .max_by(|(_, a), (_, b)| OrderedF32(b.remaining_time).cmp(&OrderedF32(a.remaining_time)).then(by_arrival(a, b)))
+                    .map(|(i, _)| i)
+                    .unwrap();
+                ready.remove(shortest).unwrap()
+            }
+            None => ready.pop_front().unwrap(),
+        };
+        if dispatched {
+            curr_time += config.dispatch_overhead;
+        }
+        if p.first_run == -1.0 {
+            p.first_run = curr_time;
+        }
+        run_tick(&mut p, config.speed);
+        add_segment(&mut segments, curr_time, curr_time + 1.0, p.pid);
+        curr_time += 1.0;
+
+        // what arrived during this tick joins the queue ahead of p, like in rr
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            ready.push_back(todo.pop_front().unwrap());
+        }
+        if p.remaining_time <= 0.0 {
+            p.completion = curr_time;
+            complete.push(p);
+        } else if sjf_window(curr_time - 1.0) && sjf_window(curr_time) {
+            running = Some(p);
+        } else {
+            ready.push_back(p);
+        }
+    }
+
+    // only non-empty if the loop was interrupted
+    let mut incomplete: Vec<Process> = running.into_iter().chain(ready).chain(todo).collect();
+    record_ready_wait(&mut complete, &segments);
+    incomplete.sort_by_key(|p| p.pid);
+    ScheduleResult { processes: complete, segments, incomplete, queue_lengths, segment_cpus: Vec::new() }
+}
+
+// runs SPN (shortest process next) with predicted burst lengths
+// unlike SJF this never looks at the real durations, every time the CPU is free it runs the next burst of whichever
+// ready process has the smallest predicted burst (see predict_bursts). When a burst finishes the process goes back
+// into the ready heap, since there is no I/O being simulated it is ready again right away
+// input: borrowed Vector of Processes, alpha, output: ScheduleResult
+pub fn spn_predicted(workload: &Vec<Process>, alpha: f32) -> ScheduleResult {
+    let mut wkld: VecDeque<Process> = workload.iter().map(Process::reset).collect();
+    let n = wkld.len();
+    let mut complete : Vec<Process> = Vec::new();
+    let mut segments = Vec::new();
+    let mut curr_time = wkld.front().unwrap().arrival;
+
+    // the heap holds tuples of (process, index of its next burst, estimates for all its bursts)
+    let mut ready = binary_heap_plus::BinaryHeap::from_vec_cmp(vec![],
+        |a: &(Process, usize, Vec<f32>), b: &(Process, usize, Vec<f32>)| OrderedF32(b.2[b.1]).cmp(&OrderedF32(a.2[a.1])).then(by_arrival(&a.0, &b.0)));
+
+    while complete.len() != n {
+        while !wkld.is_empty() && curr_time >= wkld.front().unwrap().arrival {
+            let mut p = wkld.pop_front().unwrap();
+            p.first_run = -1.0;
+            let estimates = predict_bursts(&p.bursts, alpha);
+            ready.push((p, 0, estimates));
+        }
+
+        // nothing is ready, skip ahead to the next arrival
+        if ready.is_empty() {
+            curr_time = wkld.front().unwrap().arrival;
+            continue;
+        }
+
+        let (mut p, burst, estimates) = ready.pop().unwrap();
+        if p.first_run == -1.0 {
+            p.first_run = curr_time;
+        }
+        add_segment(&mut segments, curr_time, curr_time + p.bursts[burst], p.pid);
+        curr_time += p.bursts[burst];
+
+        if burst + 1 == p.bursts.len() {
+            p.completion = curr_time;
+            complete.push(p);
+        } else {
+            ready.push((p, burst + 1, estimates));
+        }
+    }
+
+    ScheduleResult { processes: complete, segments, incomplete: Vec::new(), queue_lengths: Vec::new(), segment_cpus: Vec::new() }
+}
+
+// computes the completion times the workload would have under ideal processor sharing, where the CPU is split evenly
+// between every process that has arrived and not completed, all at once and continuously. RR with a quantum that
+// shrinks to 0 becomes this, so it is the fairness baseline a real RR can be compared against. Instead of going a
+// tick at a time this jumps from event to event: while n processes are active each one gets 1/n of the CPU, so the
+// next event is either the next arrival or the active process with the least work left completing, after n times
+// that work
+// input: borrowed Vector of Processes, output: Vector of Processes with first_run (their arrival, PS never makes a
+// process wait) and completion filled in, in completion order
+pub fn processor_sharing(workload: &Vec<Process>) -> Vec<Process> {
+    let mut todo: VecDeque<Process> = workload.iter().map(|p| p.reset()).collect();
+    todo.make_contiguous().sort_by_key(|p| OrderedF32(p.arrival));
+    let mut active: Vec<Process> = Vec::new();
+    let mut complete: Vec<Process> = Vec::new();
+    let mut curr_time = 0.0;
+    // floats drift a little when remaining times are divided and subtracted, anything this close to 0 is done
+    let epsilon = 1e-4;
+
+    while !todo.is_empty() || !active.is_empty() {
+        while !todo.is_empty() && curr_time >= todo.front().unwrap().arrival {
+            let mut p = todo.pop_front().unwrap();
+            p.first_run = p.arrival;
+            active.push(p);
+        }
+        if active.is_empty() {
+            curr_time = todo.front().unwrap().arrival;
+            continue;
+        }
+
+        let n = active.len() as f32;
+        let least = active.iter().map(|p| p.remaining_time).fold(f32::MAX, f32::min);
+        let next_arrival = todo.front().map_or(f32::MAX, |p| p.arrival);
+        // how long until the next event, every active process does step / n work in that time
+        let step = (least * n).min(next_arrival - curr_time);
+        curr_time += step;
+        for p in active.iter_mut() {
+            p.remaining_time -= step / n;
+        }
+
+        let (done, still_active): (Vec<Process>, Vec<Process>) = active.into_iter().partition(|p| p.remaining_time <= epsilon);
+        active = still_active;
+        for mut p in done {
+            p.remaining_time = 0.0;
+            p.completion = curr_time;
+            complete.push(p);
         }
-        counter += 1;
     }
+
     complete
 }
@@ -0,0 +1,41 @@
+// Tests for edf with --abort-on-miss on oversubscribed.txt: pid 0 (4 long, due at 5) runs first, and while it does
+// pid 1 (4 long, due at 6) runs out of time. At t=3 there are only 3 ticks left until its deadline and it still needs 4,
+// so that is where the miss is sure, and where stop should end the simulation. skip drops pid 1 there instead and
+// still runs pid 2.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs edf with --abort-on-miss and the action, and returns what it prints
+fn run(action: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("oversubscribed.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("edf")
+        .arg(workload)
+        .args(["--abort-on-miss", action])
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn stop_at_the_first_sure_miss() {
+    let stdout = run("stop");
+    // every process arrives at 0, so the warning about that comes before anything runs
+    let mut lines = stdout.lines();
+    assert_eq!(lines.next().unwrap(), "Warning: all processes arrive simultaneously; response times reflect queue position only");
+    assert_eq!(lines.next().unwrap(), "Deadline Miss: pid 1 can't complete by its deadline 6 anymore, 4 left at t=3, stopping");
+    // nothing had completed yet, pid 0 still had 1 tick left
+    assert!(stdout.contains("3 processes incomplete"));
+    assert!(stdout.contains("pid=0, arrival=0, duration=4, remaining_time=1"));
+}
+
+#[test]
+fn skip_drops_only_that_job() {
+    let stdout = run("skip");
+    let misses: Vec<&str> = stdout.lines().filter(|l| l.starts_with("Deadline Miss:")).collect();
+    assert_eq!(misses, vec!["Deadline Miss: pid 1 can't complete by its deadline 6 anymore, 4 left at t=3, dropping it"]);
+    assert!(stdout.contains("pid=2, arrival=0, duration=3, first_run=4, completion=7"));
+    assert!(stdout.contains("1 processes incomplete"));
+}
@@ -0,0 +1,61 @@
+// Tests for rr-multi with the affinity column on affinity.txt, two processes of 4 that both want CPU 1, with 2 CPUs. By
+// default they wait for CPU 1 and take turns on it a tick at a time, each waiting only a tick at a time, less than
+// --affinity-wait, while CPU 0 stays idle the whole run. So they complete at 7 and 8 instead of both at 4. With
+// --affinity-wait 0 nothing waits for its CPU, pid 0 is the first in the queue every tick and takes CPU 0 all 4 times.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr-multi on affinity.txt with 2 CPUs and the arguments and returns what it prints
+fn run(args: &[&str]) -> String {
+    run_algorithm("rr-multi", args)
+}
+
+// runs the algorithm on affinity.txt with 2 CPUs and the arguments and returns what it prints
+fn run_algorithm(algo: &str, args: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("affinity.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--cpus", "2"])
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn waits_for_the_affinity_cpu() {
+    let stdout = run(&[]);
+    assert!(stdout.contains("pid=0, arrival=0, duration=4, first_run=0, completion=7"), "{stdout}");
+    assert!(stdout.contains("pid=1, arrival=0, duration=4, first_run=1, completion=8"), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "Affinity Violations:     0 time slices off the affinity CPU"), "{stdout}");
+}
+
+#[test]
+fn falls_back_once_the_wait_is_up() {
+    let stdout = run(&["--affinity-wait", "0"]);
+    assert!(stdout.contains("pid=0, arrival=0, duration=4, first_run=0, completion=4"), "{stdout}");
+    assert!(stdout.contains("pid=1, arrival=0, duration=4, first_run=0, completion=4"), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "Affinity Violations:     4 time slices off the affinity CPU (pids: 0)"), "{stdout}");
+}
+
+// every slice is a dispatch, so with an overhead of 1 CPU 1 spends a tick on it before each slice, and at speed 2 a
+// slice does 2 of work, 2 slices each. pid 0 runs 1-2 and 5-6, pid 1 3-4 and 7-8
+#[test]
+fn charges_the_overhead_every_slice() {
+    let stdout = run(&["--speed", "2", "--dispatch-overhead", "1", "--check-conservation"]);
+    assert!(stdout.contains("pid=0, arrival=0, duration=4, first_run=1, completion=6"), "{stdout}");
+    assert!(stdout.contains("pid=1, arrival=0, duration=4, first_run=3, completion=8"), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "Conservation: OK"), "{stdout}");
+}
+
+// the other algorithms ignore the affinity column, so they don't report violations they never counted
+#[test]
+fn only_rr_multi_reports_violations() {
+    for algo in ["fifo", "rr", "cfs", "gang"] {
+        let stdout = run_algorithm(algo, &[]);
+        assert!(!stdout.contains("Affinity Violations:"), "{algo}: {stdout}");
+    }
+}
@@ -0,0 +1,40 @@
+// Tests for the aging algorithm on aging.txt: pid 0 has priority 5 and arrives at 0, and a new priority 0 process
+// arrives every tick from 0 to 39, so there is always a higher priority process ready until 40. Under pure priority
+// scheduling pid 0 starves until the stream ends. With aging its effective priority gets better every tick it waits,
+// so it should get the CPU long before that.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs an algorithm on aging.txt and returns pid 0's (first_run, completion)
+fn low_priority(args: &[&str]) -> (f32, f32) {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("aging.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(args[0])
+        .arg(workload)
+        .args(&args[1..])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = stdout.lines().find(|l| l.trim().starts_with("pid=0,")).unwrap();
+    let field = |name: &str| -> f32 {
+        line.split(", ").find_map(|f| f.strip_prefix(name)).unwrap().parse().unwrap()
+    };
+    (field("first_run="), field("completion="))
+}
+
+#[test]
+fn pure_priority_starves() {
+    assert_eq!(low_priority(&["priority"]), (40.0, 42.0));
+    // without aging, aging is the same thing made preemptive
+    assert_eq!(low_priority(&["aging", "--aging-rate", "0"]), (40.0, 42.0));
+}
+
+#[test]
+fn aging_lets_it_run() {
+    // after 10 ticks of waiting 5 - 0.5 * 10 ties the newest arrival, and the earlier arrival wins the tie
+    let (first_run, completion) = low_priority(&["aging", "--aging-rate", "0.5"]);
+    assert_eq!(first_run, 10.0);
+    assert!(completion < 40.0);
+}
@@ -0,0 +1,24 @@
+// Test for --bucket-by-arrival 10 on fifo. arrival_ranges.txt has 3 processes arriving in [0, 10) with turnarounds
+// 4, 5 and 10, 2 in [10, 20) with 5 and 4 (they wait behind the first ones), and 1 at 25 that runs right away for 2.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn averages_per_bucket() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("arrival_ranges.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .args(["--bucket-by-arrival", "10"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let buckets: Vec<&str> = stdout.lines().skip_while(|l| *l != "Turnaround by Arrival:").skip(1).map(|l| l.trim()).collect();
+    assert_eq!(buckets, vec![
+        "[0, 10): 3 processes, average turnaround 6.3333335",
+        "[10, 20): 2 processes, average turnaround 4.5",
+        "[20, 30): 1 processes, average turnaround 2",
+    ]);
+}
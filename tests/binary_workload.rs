@@ -0,0 +1,45 @@
+// Tests for the .bin workload format. mixed_40.txt is converted to a .bin file, and running an algorithm on it should
+// then give exactly the same processes as running it on the text file, pids included. A .bin file whose count doesn't
+// match its size should be refused.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// runs the binary with the arguments and returns what it prints
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).args(args).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// a path in the temp folder that no other test run uses
+fn temp(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("final_scheduler_binary_{}_{name}", std::process::id()))
+}
+
+#[test]
+fn round_trip() {
+    let text = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("mixed_40.txt");
+    let binary = temp("workload.bin");
+    assert_eq!(run(&["convert", text.to_str().unwrap(), binary.to_str().unwrap()]), "");
+    // the count and then 8 bytes per process
+    assert_eq!(fs::read(&binary).unwrap().len(), 4 + 40 * 8);
+    for algo in ["fifo", "rr", "stcf"] {
+        assert_eq!(run(&[algo, binary.to_str().unwrap()]), run(&[algo, text.to_str().unwrap()]), "{algo}");
+    }
+    fs::remove_file(binary).unwrap();
+}
+
+#[test]
+fn wrong_size() {
+    let binary = temp("short.bin");
+    // says 2 processes but only has 1
+    let mut bytes = 2u32.to_le_bytes().to_vec();
+    bytes.extend_from_slice(&0.0f32.to_le_bytes());
+    bytes.extend_from_slice(&5.0f32.to_le_bytes());
+    fs::write(&binary, bytes).unwrap();
+    let stdout = run(&["fifo", binary.to_str().unwrap()]);
+    assert_eq!(stdout.lines().next().unwrap(), "Error: the binary workload says it has 2 processes but has 8 bytes of them instead of 16");
+    fs::remove_file(binary).unwrap();
+}
@@ -0,0 +1,32 @@
+// Tests for mlfq's --boost policy. one_long.txt is a single duration 20 process, which mlfq moves down a level every
+// tick until it sits in the last of the 4 levels by t=3. The first boost happens at t=9, and the compact state line
+// printed right after it shows which level the process was moved to.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs mlfq on one_long.txt with the boost policy and returns the state line printed at t=9
+fn state_after_boost(policy: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("one_long.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("mlfq")
+        .arg(workload)
+        .args(["--mlfq-format", "compact", "--boost", policy])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // it was in the last level just before
+    assert!(stdout.lines().any(|l| l == "t=8 [0,0,0,1]"), "{stdout}");
+    stdout.lines().find(|l| l.starts_with("t=9 ")).unwrap().to_string()
+}
+
+#[test]
+fn one_level_moves_up_one() {
+    assert_eq!(state_after_boost("one-level"), "t=9 [0,0,1,0]");
+}
+
+#[test]
+fn to_top_moves_to_the_first_level() {
+    assert_eq!(state_after_boost("to-top"), "t=9 [1,0,0,0]");
+}
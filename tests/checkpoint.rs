@@ -0,0 +1,62 @@
+// Tests for --checkpoint and --resume with rr. Stopping a run partway with --stop-at and resuming it from the
+// checkpoint should print exactly what the run prints without stopping, the processes and every metric. mixed_40.txt is
+// stopped in the middle with a quantum of 2, which the resumed run has to take from the checkpoint. suspend.txt with its
+// events is stopped after its process was suspended and resumed, and the ready_wait it ends up with depends on the
+// suspension, so that has to be carried over too.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// runs rr on a fixture with the arguments and returns what it prints
+fn run(fixture: &str, args: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("rr").arg(workload).args(args).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// a path in the temp folder that no other test run uses
+fn temp(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("final_scheduler_checkpoint_{}_{name}", std::process::id()))
+}
+
+#[test]
+fn stop_and_resume_is_one_run() {
+    let state = temp("mixed.txt");
+    let state_path = state.to_str().unwrap();
+    let full = run("mixed_40.txt", &["--quantum", "2"]);
+    let stopped = run("mixed_40.txt", &["--quantum", "2", "--checkpoint", state_path, "--stop-at", "200"]);
+    // the first slice that ends at or after 200 ends at 201
+    assert_eq!(stopped.lines().next().unwrap(), format!("Checkpoint: stopped at t=201, saved to {state_path}"));
+    assert!(stopped.contains("processes incomplete"));
+    assert_eq!(run("mixed_40.txt", &["--resume", state_path]), full);
+    fs::remove_file(state).unwrap();
+}
+
+#[test]
+fn suspensions_are_carried_over() {
+    let state = temp("suspend.txt");
+    let state_path = state.to_str().unwrap();
+    let events = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("events").join("suspend_5.txt");
+    let events = events.to_str().unwrap();
+    // the quantum is the default, it is given so the full run doesn't print the note suggesting one, which a resumed run
+    // never prints
+    let full = run("suspend.txt", &["--events", events, "--quantum", "1"]);
+    // nothing runs while it is suspended, so the first slice to end after 3 is the one after the resume at 6
+    let stopped = run("suspend.txt", &["--events", events, "--checkpoint", state_path, "--stop-at", "3"]);
+    assert!(stopped.starts_with("Checkpoint: stopped at t=7"));
+    assert_eq!(run("suspend.txt", &["--resume", state_path]), full);
+    fs::remove_file(state).unwrap();
+}
+
+// without --stop-at the run goes to the end, and the file has the last checkpoint it passed
+#[test]
+fn periodic_checkpoints() {
+    let state = temp("every.txt");
+    let state_path = state.to_str().unwrap();
+    let full = run("mixed_40.txt", &["--checkpoint", state_path, "--checkpoint-every", "50"]);
+    assert!(fs::read_to_string(&state).unwrap().lines().any(|l| l == "time 400"));
+    assert_eq!(run("mixed_40.txt", &["--resume", state_path]), full);
+    fs::remove_file(state).unwrap();
+}
@@ -0,0 +1,21 @@
+// Test for "all" with --only: the comparison table should have a header and then exactly one row per requested
+// algorithm, in the order they were asked for.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn only_limits_the_rows() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("all")
+        .arg(workload)
+        .args(["--only", "rr,fifo,sjf"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // the first word of every row after the header is the algorithm
+    let rows: Vec<&str> = stdout.lines().skip(1).map(|l| l.split_whitespace().next().unwrap()).collect();
+    assert_eq!(rows, vec!["rr", "fifo", "sjf"]);
+}
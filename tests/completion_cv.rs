@@ -0,0 +1,33 @@
+// Test for --completion-cv. A process of duration 10 arrives at 0 and three of duration 1 arrive right after it. fifo
+// runs the long one first and then completes the short ones a tick apart, at 10, 11, 12 and 13, the same gap every
+// time. stcf runs the short ones as they arrive, completing at 2, 3 and 4, and then the long one not until 13, so its
+// gaps are bursty and its coefficient of variation is higher.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+const WORKLOAD: &str = "0 10\n1 1\n2 1\n3 1\n";
+
+// runs an algorithm on WORKLOAD with --completion-cv and returns the number it prints
+fn cv(algo: &str) -> f32 {
+    let path = env::temp_dir().join(format!("final_scheduler_completion_cv_{algo}_{}.txt", std::process::id()));
+    fs::write(&path, WORKLOAD).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(&path)
+        .arg("--completion-cv")
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find_map(|l| l.strip_prefix("Completion Gap CV:")).unwrap().trim().parse().unwrap()
+}
+
+#[test]
+fn stcf_is_burstier_than_fifo() {
+    let fifo = cv("fifo");
+    let stcf = cv("stcf");
+    assert_eq!(fifo, 0.0);
+    assert!(stcf > fifo, "stcf {stcf}, fifo {fifo}");
+}
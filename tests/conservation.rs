@@ -0,0 +1,41 @@
+// Tests for --check-conservation: for every algorithm the CPU time in the run segments has to add up to the durations
+// of the processes in mixed_40.txt. The check exits with an error code when it fails, so the exit status is checked
+// as well as the OK line. The speed and cpu_budget cases are the ones where a process gets less or more CPU time than
+// its duration, so the check has to account for them instead of just adding up durations.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on the fixture with --check-conservation and the extra arguments, true if the check passed
+fn conserved(algo: &str, fixture: &str, extra: &[&str]) -> bool {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--check-conservation", "--quiet"])
+        .args(extra)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    output.status.success() && stdout.lines().any(|l| l == "Conservation: OK")
+}
+
+#[test]
+fn every_algorithm() {
+    for algo in ["fifo", "sjf", "stcf", "rr", "mlfq", "spn", "priority", "gang", "two-level", "cfs", "edf", "hybrid", "ljf", "aging", "memory", "smith", "rr-classed", "rr-multi"] {
+        assert!(conserved(algo, "mixed_40.txt", &[]), "{algo}");
+    }
+}
+
+#[test]
+fn slower_cpu() {
+    for algo in ["stcf", "rr", "mlfq"] {
+        assert!(conserved(algo, "mixed_40.txt", &["--speed", "0.3"]), "{algo}");
+    }
+}
+
+#[test]
+fn exhausted_budgets() {
+    assert!(conserved("rr", "cpu_budget.txt", &[]));
+}
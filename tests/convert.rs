@@ -0,0 +1,39 @@
+// Test for the convert command. all_columns.json uses every optional column, converting it to the whitespace format
+// and back to JSON should give back the same workload: the same JSON text, and the same processes when it is run.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// runs the binary with the arguments and returns what it prints
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).args(args).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// a path in the temp folder that no other test run uses
+fn temp(name: &str) -> PathBuf {
+    env::temp_dir().join(format!("final_scheduler_convert_{}_{name}", std::process::id()))
+}
+
+#[test]
+fn json_round_trip() {
+    let original = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("all_columns.json");
+    let text = temp("workload.txt");
+    let json = temp("workload.json");
+    assert_eq!(run(&["convert", original.to_str().unwrap(), text.to_str().unwrap()]), "");
+    assert_eq!(run(&["convert", text.to_str().unwrap(), json.to_str().unwrap()]), "");
+
+    assert_eq!(fs::read_to_string(&text).unwrap(), "0 12 bursts=2,2,8 priority=1 weight=2 start_level=2\n\
+                                                    3 4 preemptible=false affinity=1 memory=3 deadline=9 hard_deadline=true\n\
+                                                    1 6 group=2 cpu_budget=4 yield_interval=2 period=10 depends_on=0\n");
+    assert_eq!(fs::read_to_string(&json).unwrap(), fs::read_to_string(&original).unwrap());
+    // the processes read from all three files are the same, pids included
+    let original_run = run(&["rr", original.to_str().unwrap(), "--deadlines"]);
+    assert_eq!(run(&["rr", text.to_str().unwrap(), "--deadlines"]), original_run);
+    assert_eq!(run(&["rr", json.to_str().unwrap(), "--deadlines"]), original_run);
+
+    fs::remove_file(text).unwrap();
+    fs::remove_file(json).unwrap();
+}
@@ -0,0 +1,59 @@
+// Tests for --core-trace. gang and rr-multi are the algorithms that simulate more than one CPU. gang is checked on
+// gang.txt with 2 CPUs: pid 2 arrives alone at 0 and runs on cpu0 while cpu1 is idle, then the gang of pids 0 and 1
+// (ready at 1) has to wait for both CPUs and runs on both from 2 to 6. rr-multi is checked on affinity.txt, where both
+// processes want CPU 1, so they take turns on it a tick at a time and cpu0 stays idle the whole run. The single CPU
+// algorithms refuse the option.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs an algorithm on the fixture with --cpus 2 --core-trace and returns what it prints
+fn run(algo: &str, fixture: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--cpus", "2", "--core-trace"])
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// the lines after "Core Trace:"
+fn trace(stdout: &str) -> Vec<&str> {
+    stdout.lines().skip_while(|l| *l != "Core Trace:").skip(1).map(|l| l.trim()).collect()
+}
+
+#[test]
+fn gang_on_two_cpus() {
+    let stdout = run("gang", "gang.txt");
+    assert_eq!(trace(&stdout), vec![
+        "t=0: cpu0=2 cpu1=idle",
+        "t=1: cpu0=2 cpu1=idle",
+        "t=2: cpu0=0 cpu1=1",
+        "t=3: cpu0=0 cpu1=1",
+        "t=4: cpu0=0 cpu1=1",
+        "t=5: cpu0=0 cpu1=1",
+    ]);
+}
+
+#[test]
+fn rr_multi_waits_for_the_affinity_cpu() {
+    let stdout = run("rr-multi", "affinity.txt");
+    assert_eq!(trace(&stdout), vec![
+        "t=0: cpu0=idle cpu1=0",
+        "t=1: cpu0=idle cpu1=1",
+        "t=2: cpu0=idle cpu1=0",
+        "t=3: cpu0=idle cpu1=1",
+        "t=4: cpu0=idle cpu1=0",
+        "t=5: cpu0=idle cpu1=1",
+        "t=6: cpu0=idle cpu1=0",
+        "t=7: cpu0=idle cpu1=1",
+    ]);
+}
+
+#[test]
+fn single_cpu_algorithms_refuse() {
+    assert!(run("rr", "gang.txt").starts_with("Error: --core-trace only works with gang and rr-multi"));
+}
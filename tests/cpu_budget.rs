@@ -0,0 +1,22 @@
+// Test for the cpu_budget column under rr. In cpu_budget.txt pid 0 needs 10 ticks but is only allowed 4, so rr should
+// complete it after its 4th tick, long before it could have finished. pid 1's budget is more than it needs, so it just
+// finishes normally.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn budget_completes_early() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("cpu_budget.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let pid0 = stdout.lines().find(|l| l.trim().starts_with("pid=0,")).unwrap();
+    // rr alternates pid 0 with the others, so its 4th tick is the one from 6 to 7
+    assert!(pid0.contains("completion=7,"), "{pid0}");
+    assert!(stdout.lines().any(|l| l == "Budget Exhausted:        1 (pids: 0)"), "{stdout}");
+}
@@ -0,0 +1,43 @@
+// Tests for --csv and --annotate-csv. fifo on staggered.txt runs the processes in order, so pid 1 (arrival 1, duration
+// 4) runs from 8 to 12: turnaround 11, response 7, waiting 7 and normalized turnaround 2.75. The AVG row should match
+// the averages fifo prints, 15.25 turnaround and 8.75 response.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on staggered.txt writing a CSV with the extra arguments and returns the CSV's lines
+fn csv(name: &str, extra: &[&str]) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let path = env::temp_dir().join(format!("final_scheduler_{name}_{}.csv", std::process::id()));
+    Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .arg("--csv")
+        .arg(&path)
+        .args(extra)
+        .output()
+        .unwrap();
+    let text = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    text.lines().map(|l| l.to_string()).collect()
+}
+
+#[test]
+fn annotated() {
+    let lines = csv("annotated", &["--annotate-csv"]);
+    assert_eq!(lines[0], "pid,arrival,duration,first_run,completion,turnaround,response,waiting,normalized_turnaround");
+    assert_eq!(lines[2], "1,1,4,8,12,11,7,7,2.75");
+    let summary: Vec<&str> = lines.last().unwrap().split(',').collect();
+    assert_eq!(summary[..8], ["AVG", "", "", "", "", "15.25", "8.75", "8.75"]);
+    assert_eq!(lines.len(), 6);
+}
+
+#[test]
+fn plain() {
+    let lines = csv("plain", &[]);
+    assert_eq!(lines[0], "pid,arrival,duration,first_run,completion");
+    assert_eq!(lines[2], "1,1,4,8,12");
+    assert_eq!(lines.len(), 5);
+}
@@ -0,0 +1,14 @@
+// Test for custom_schedule. The binary has no library to call it from, so selftest runs FIFO written as a closure on
+// its generated workloads and compares the schedule with fifo's, and this checks that it passed.
+
+use std::process::Command;
+
+#[test]
+fn fifo_as_a_closure_matches_fifo() {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("selftest")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "custom_schedule (fifo as a closure): PASS"), "{stdout}");
+}
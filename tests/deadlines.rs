@@ -0,0 +1,38 @@
+// Tests for --deadlines. Both fixtures run under fifo: in hard_deadline_miss.txt pid 1 has a hard deadline of 5 but
+// waits behind pid 0 until 3 and completes at 7, in soft_deadlines.txt pids 0 and 2 complete 1 and 3 ticks late and
+// none of the deadlines are hard.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on a fixture with --deadlines and returns the lines from "Deadline Misses:" on
+fn deadline_report(fixture: &str) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .arg("--deadlines")
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+        .lines()
+        .skip_while(|l| *l != "Deadline Misses:")
+        .map(|l| l.to_string())
+        .collect()
+}
+
+#[test]
+fn hard_miss_is_critical() {
+    let report = deadline_report("hard_deadline_miss.txt");
+    assert!(report.contains(&"\tCRITICAL pid=1 missed its hard deadline 5, completed at 7".to_string()), "{report:?}");
+    assert!(report.contains(&"Hard Deadline Misses:    1 (CRITICAL, this schedule is not valid)".to_string()), "{report:?}");
+}
+
+#[test]
+fn soft_misses_add_up_tardiness() {
+    let report = deadline_report("soft_deadlines.txt");
+    assert!(!report.iter().any(|l| l.contains("CRITICAL")), "{report:?}");
+    assert!(report.contains(&"Hard Deadline Misses:    0".to_string()), "{report:?}");
+    assert!(report.contains(&"Soft Deadline Tardiness: 4".to_string()), "{report:?}");
+}
@@ -0,0 +1,34 @@
+// Tests for --demotion. equal_pair.txt has two duration 4 processes arriving together, and mlfq prints how many
+// processes are in each level every tick. With full-slice demotion (the default) a process moves down every time it
+// uses its 1 tick slice, so the pair walks down a level every tick they share. With allotment=2 each one only moves
+// down after 2 ticks at a level, so they stay in the top level for the first 3 ticks and never get past the second.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs mlfq on equal_pair.txt with the extra options and returns the level occupancy lines it prints
+fn occupancy(extra: &[&str]) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("equal_pair.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("mlfq")
+        .arg(workload)
+        .args(extra)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().filter(|l| l.starts_with("t=")).map(|l| l.to_string()).collect()
+}
+
+#[test]
+fn full_slice_is_the_default() {
+    let expected = vec!["t=0 [2,0,0,0]", "t=1 [1,1,0,0]", "t=2 [0,2,0,0]", "t=3 [0,1,1,0]", "t=4 [0,0,2,0]", "t=5 [0,0,1,1]", "t=6 [0,0,0,2]", "t=7 [0,0,0,1]"];
+    assert_eq!(occupancy(&[]), expected);
+    assert_eq!(occupancy(&["--demotion", "full-slice"]), expected);
+}
+
+#[test]
+fn allotment_keeps_processes_higher() {
+    let expected = vec!["t=0 [2,0,0,0]", "t=1 [2,0,0,0]", "t=2 [2,0,0,0]", "t=3 [1,1,0,0]", "t=4 [0,2,0,0]", "t=5 [0,2,0,0]", "t=6 [0,2,0,0]", "t=7 [0,1,0,0]"];
+    assert_eq!(occupancy(&["--demotion", "allotment=2"]), expected);
+}
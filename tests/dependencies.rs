@@ -0,0 +1,63 @@
+// Tests for the depends_on column. chain.txt is a chain A -> B -> C where everything arrives at 0, so however many CPUs
+// there are each one still has to wait for the one before it and the critical path is all three durations added up.
+// depends_on.txt has a process that arrives before another but depends on the first process, so fifo has to run the
+// later arrival first. --shuffle-ties hands out the pids of equal arrivals again, and a depends_on has to follow the
+// process it named to its new pid.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo with --critical-path on a fixture and returns what it prints
+fn run(fixture: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(path)
+        .arg("--critical-path")
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// the value printed after a label like "Critical Path:"
+fn value(stdout: &str, label: &str) -> f32 {
+    stdout.lines().find_map(|l| l.strip_prefix(label)).unwrap().trim().parse().unwrap()
+}
+
+#[test]
+fn chain_is_the_sum_of_its_durations() {
+    let stdout = run("chain.txt");
+    assert_eq!(value(&stdout, "Critical Path:"), 3.0 + 4.0 + 5.0);
+    // one CPU can't do better than the chain either
+    assert_eq!(value(&stdout, "Makespan:"), 12.0);
+}
+
+#[test]
+fn fifo_waits_for_the_dependency() {
+    let stdout = run("depends_on.txt");
+    let order: Vec<&str> = stdout.lines().filter_map(|l| l.trim().strip_prefix("pid=")).map(|l| l.split(',').next().unwrap()).collect();
+    assert_eq!(order, vec!["0", "2", "1"]);
+    // pid 1 only becomes ready when pid 0 completes at 5, pid 2 was ready at 3
+    assert!(stdout.contains("pid=1, arrival=1, duration=2, first_run=8, completion=10"));
+    // with more CPUs pid 1 starts at 5 and pid 2 at 3, so only the chain 0 -> 1 is left
+    assert_eq!(value(&stdout, "Critical Path:"), 7.0);
+}
+
+// the duration 2 process at 0 is pid 1 in the file and the last line depends on it. Seed 2 swaps the two arrivals at 0,
+// so it becomes pid 0 and the dependency has to move with it: the chain is 2 + 3, not the duration 5 process + 3
+#[test]
+fn shuffled_ties_keep_the_dependency() {
+    let path = env::temp_dir().join(format!("final_scheduler_shuffled_dependency_{}.txt", std::process::id()));
+    std::fs::write(&path, "0 5\n0 2\n1 3 depends_on=1\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(&path)
+        .args(["--shuffle-ties", "2", "--critical-path"])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("pid=0, arrival=0, duration=2"), "{stdout}");
+    assert_eq!(value(&stdout, "Critical Path:"), 5.0);
+}
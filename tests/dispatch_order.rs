@@ -0,0 +1,31 @@
+// Tests for --dispatch-order. equal_pair.txt has two duration 4 processes arriving together, rr with a quantum of 2
+// gives each 2 slices and they take turns, so the pids go 0 1 0 1. fifo runs each once, so its dispatch order is just
+// its completion order.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on equal_pair.txt with the extra options and returns the dispatch order line
+fn dispatch_order(algo: &str, extra: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("equal_pair.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--dispatch-order")
+        .args(extra)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("Dispatch Order:")).unwrap().to_string()
+}
+
+#[test]
+fn rr_interleaves() {
+    assert_eq!(dispatch_order("rr", &["--quantum", "2"]), "Dispatch Order: 0 1 0 1");
+}
+
+#[test]
+fn fifo_is_completion_order() {
+    assert_eq!(dispatch_order("fifo", &[]), "Dispatch Order: 0 1");
+}
@@ -0,0 +1,31 @@
+// Test for Process's Display. Every process line show_processes prints is a tab, the Display string, and then the
+// initial and ready waits, so fifo on staggered.txt shows exactly what Display gives for a known process. pid 1
+// arrives at 1 with duration 4 and fifo runs it after pid 0, from 8 to 12.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on staggered.txt with the extra options and returns the line it prints for pid 1
+fn pid1_line(extra: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .args(extra)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("\tpid=1,")).unwrap().to_string()
+}
+
+#[test]
+fn known_process() {
+    assert_eq!(pid1_line(&[]), "\tpid=1, arrival=1, duration=4, first_run=8, completion=12, initial_wait=7, ready_wait=0");
+}
+
+// --relative only shifts the printed first_run and completion, the Display fields stay in the same order
+#[test]
+fn relative_process() {
+    assert_eq!(pid1_line(&["--relative"]), "\tpid=1, arrival=1, duration=4, first_run=7, completion=11, initial_wait=7, ready_wait=0");
+}
@@ -0,0 +1,26 @@
+// Test for --duration-histogram. durations.txt has durations 1, 1, 2, 5, 9 and 10, so the range 1 to 10 splits into 5
+// buckets 2 wide, and the two empty ones in the middle still get a row.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn bucket_counts() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("durations.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .arg("--duration-histogram")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // every row is "range | bars count", keep the range and the count
+    let buckets: Vec<(&str, &str)> = stdout.lines()
+        .skip_while(|l| *l != "Duration Histogram:")
+        .skip(1)
+        .take_while(|l| l.starts_with('\t'))
+        .map(|l| (l.split('|').next().unwrap().trim(), l.split_whitespace().last().unwrap()))
+        .collect();
+    assert_eq!(buckets, vec![("1-2", "3"), ("3-4", "0"), ("5-6", "1"), ("7-8", "0"), ("9-10", "2")]);
+}
@@ -0,0 +1,16 @@
+// Test for check_no_early_start. The binary has no library to call it from, so selftest takes a fifo schedule, moves
+// one process's first run to a tick before it arrived, and prints whether the check flagged it and what it said.
+
+use std::process::Command;
+
+#[test]
+fn flags_a_process_run_before_it_arrives() {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("selftest")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = stdout.lines().find(|l| l.starts_with("check_no_early_start")).unwrap();
+    assert!(line.starts_with("check_no_early_start (a process run before it arrives): PASS (pid "), "{line}");
+    assert!(line.contains(" before arriving at "), "{line}");
+}
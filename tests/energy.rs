@@ -0,0 +1,31 @@
+// Tests for --energy. The CPU uses 1 unit of power per unit of time busy and 0.1 idle (ACTIVEPOWER and IDLEPOWER in
+// main.rs). single.txt is one duration 5 process, busy 5 and never idle. idle_gap.txt runs a duration 3 process, sits
+// idle from 3 until the next one arrives at 5, and then runs a duration 2 one, busy 5 and idle 2.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on the fixture with --energy and returns the energy it prints
+fn energy(fixture: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .arg("--energy")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("Energy:")).unwrap().split_whitespace().last().unwrap().to_string()
+}
+
+#[test]
+fn busy_only() {
+    assert_eq!(energy("single.txt"), "5");
+}
+
+#[test]
+fn busy_and_idle() {
+    // 5 * 1 + 2 * 0.1
+    assert_eq!(energy("idle_gap.txt"), "5.2");
+}
@@ -0,0 +1,42 @@
+// Test for --compare-fairness. mixed_40.txt mixes short and long jobs arriving over time. sjf keeps putting the long
+// jobs behind every short one that arrives, so their turnarounds end up far longer than the rest, while rr shares the
+// CPU and keeps them closer together. So rr has to get the higher Jain index and be listed first. A zero duration
+// process that sjf runs the moment it arrives has a turnaround of 0, and the max/min ratio is n/a instead of inf.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn rr_is_fairer_than_sjf_on_mixed_lengths() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("mixed_40.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(workload)
+        .args(["--compare-fairness", "--only", "sjf,rr"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // every row after the header is algorithm, jain, max/min turnaround
+    let rows: Vec<(String, f32)> = stdout.lines().skip(1).map(|l| {
+        let cols: Vec<&str> = l.split_whitespace().collect();
+        (cols[0].to_string(), cols[1].parse().unwrap())
+    }).collect();
+    assert_eq!(rows.len(), 2, "{stdout}");
+    assert_eq!(rows[0].0, "rr", "{stdout}");
+    assert!(rows[0].1 > rows[1].1, "{stdout}");
+}
+
+#[test]
+fn zero_turnaround_has_no_ratio() {
+    let path = env::temp_dir().join(format!("final_scheduler_fairness_zero_{}.txt", std::process::id()));
+    std::fs::write(&path, "0 0\n0 3\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(&path)
+        .args(["--compare-fairness", "--only", "sjf"])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let row: Vec<&str> = stdout.lines().nth(1).unwrap().split_whitespace().collect();
+    assert_eq!(row, vec!["sjf", "0.5000", "n/a"], "{stdout}");
+}
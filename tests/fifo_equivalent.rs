@@ -0,0 +1,37 @@
+// Test for the note printed when the algorithm can't matter. In idle_gap.txt every process arrives after the one
+// before it has completed, so every algorithm should print the note and make exactly the same schedule (the same
+// fingerprint). staggered.txt overlaps, so it gets no note.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+const NOTE: &str = "Note: every process arrives after the one before it completes, so every algorithm gives this workload the same schedule as FIFO";
+
+// runs an algorithm on a fixture with --fingerprint and returns what it prints
+fn run(algo: &str, fixture: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--fingerprint")
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn non_overlapping_is_flagged() {
+    let fifo = run("fifo", "idle_gap.txt");
+    let fingerprint = fifo.lines().find(|l| l.starts_with("Schedule Fingerprint:")).unwrap();
+    for algo in ["fifo", "sjf", "stcf", "rr", "mlfq", "spn", "priority", "gang", "two-level", "cfs", "edf", "hybrid", "ljf", "aging", "memory", "smith", "rr-classed", "rr-multi"] {
+        let stdout = run(algo, "idle_gap.txt");
+        assert!(stdout.lines().any(|l| l == NOTE), "{algo}");
+        assert!(stdout.lines().any(|l| l == fingerprint), "{algo}");
+    }
+}
+
+#[test]
+fn overlapping_is_not() {
+    assert!(!run("fifo", "staggered.txt").contains(NOTE));
+}
@@ -0,0 +1,15 @@
+// Test for schedule_fingerprint. selftest takes a fifo schedule and checks the same schedule with the processes in the
+// opposite order has the same fingerprint, while the schedule with one process completing a tick later doesn't. The
+// binary only prints one fingerprint per run, so this checks that selftest passed.
+
+use std::process::Command;
+
+#[test]
+fn order_does_not_matter_but_completions_do() {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("selftest")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "schedule_fingerprint (reordered the same, one completion changed different): PASS"), "{stdout}");
+}
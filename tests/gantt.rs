@@ -0,0 +1,52 @@
+// Test for the time axis under the --gantt chart. span_20.txt runs on the CPU from 0 to 20 without a gap, so the axis
+// should have a + every 5 columns and a label on each of them, lined up with the left edge of a column. span_100.txt
+// runs from 0 to 100, its last process only for 1 unit, to check --gantt-scale.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on a fixture with --gantt and any extra options, and returns what it prints
+fn gantt(fixture: &str, extra_args: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .arg("--gantt")
+        .args(extra_args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn axis_labels_every_five() {
+    let stdout = gantt("span_20.txt", &[]);
+    // the axis is the last 2 lines, the ticks and then the labels
+    let lines: Vec<&str> = stdout.lines().collect();
+    let ticks = lines[lines.len() - 2];
+    let labels = lines[lines.len() - 1];
+    assert_eq!(ticks.trim(), "+----+----+----+----+");
+    assert_eq!(labels.split_whitespace().collect::<Vec<&str>>(), vec!["0", "5", "10", "15", "20"]);
+    // every label starts right under its +
+    let plus: Vec<usize> = ticks.match_indices('+').map(|(i, _)| i).collect();
+    let starts: Vec<usize> = labels.match_indices(char::is_numeric)
+        .map(|(i, _)| i)
+        .filter(|&i| i == 0 || labels.as_bytes()[i - 1] == b' ')
+        .collect();
+    assert_eq!(plus, starts);
+}
+
+#[test]
+fn scale_compresses_columns() {
+    let stdout = gantt("span_100.txt", &["--gantt-scale", "10"]);
+    let rows: Vec<&str> = stdout.lines().filter(|l| l.trim().starts_with("pid ")).collect();
+    assert_eq!(rows.len(), 3);
+    for row in &rows {
+        // the bar is between the two |
+        let bar = row.split('|').nth(1).unwrap();
+        assert_eq!(bar.len(), 10, "{row}");
+    }
+    // pid 2 only ran from 99 to 100, a tenth of the last column, but is still marked
+    assert!(rows[2].ends_with(".:|"), "{}", rows[2]);
+}
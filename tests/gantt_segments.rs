@@ -0,0 +1,27 @@
+// Test for the structured Gantt data, printed by --gantt-json. stcf on staggered.txt starts pid 0, preempts it at 1
+// when the shorter pid 1 arrives, runs pid 1 and then pid 3 to completion, goes back to pid 0 and runs pid 2 last.
+// That is 5 segments, with pid 0 split in two around the others, and they cover 0 to 26 with no gaps.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn stcf_segments() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("stcf")
+        .arg(workload)
+        .arg("--gantt-json")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // the chart is the last line, {"pid": 0, "start": 0, "end": 1} for each segment
+    let chart = stdout.lines().last().unwrap();
+    let segments: Vec<(u32, u32, u32)> = chart.trim_start_matches("[{").trim_end_matches("}]").split("}, {").map(|segment| {
+        let values: Vec<u32> = segment.split(", ").map(|field| field.rsplit(' ').next().unwrap().parse().unwrap()).collect();
+        (values[0], values[1], values[2])
+    }).collect();
+    assert_eq!(segments, vec![(0, 0, 1), (1, 1, 5), (3, 5, 10), (0, 10, 17), (2, 17, 26)]);
+    assert!(segments.windows(2).all(|w| w[0].2 == w[1].1), "{chart}");
+}
@@ -0,0 +1,37 @@
+// Test for --gantt-vs: rr and fifo on staggered.txt printed as one comparison chart. Each chart's rows start with the
+// pid's color code, and a process has to get the same one in both charts, with its bar drawn in it as well.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// the color escape code a row starts with and the pid it is for, from a line like "\t\x1b[31mpid 0\x1b[0m |...|"
+fn row_color(line: &str) -> (String, usize) {
+    let (color, rest) = line.trim_start_matches('\t').split_once("pid ").unwrap();
+    let pid = rest.split('\x1b').next().unwrap().trim().parse().unwrap();
+    (color.to_string(), pid)
+}
+
+#[test]
+fn same_color_in_both_charts() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .args(["--gantt-vs", "fifo"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let chart: Vec<&str> = stdout.lines().skip_while(|l| !l.starts_with("Gantt Chart Comparison")).collect();
+    let rr_start = chart.iter().position(|l| *l == "rr").unwrap();
+    let fifo_start = chart.iter().position(|l| *l == "fifo").unwrap();
+    let rr: Vec<(String, usize)> = chart[rr_start + 1..fifo_start].iter().map(|l| row_color(l)).collect();
+    let fifo: Vec<(String, usize)> = chart[fifo_start + 1..fifo_start + 1 + rr.len()].iter().map(|l| row_color(l)).collect();
+
+    assert_eq!(rr.len(), 4);
+    assert_eq!(rr, fifo);
+    // pid 2 has a color of its own, and its bar is drawn in it
+    let (color, _) = rr.iter().find(|(_, pid)| *pid == 2).unwrap();
+    assert!(rr.iter().filter(|(c, _)| c == color).count() == 1);
+    assert!(chart[fifo_start + 3].contains(&format!("{color}#")));
+}
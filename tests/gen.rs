@@ -0,0 +1,49 @@
+// Tests for the gen command's generators. Options that would make a generator divide by zero or wrap around have to be
+// turned down with an error before anything is generated, and the Poisson model's gaps have to average out to lambda.
+
+use std::process::Command;
+
+// runs gen with the given arguments and returns what it printed
+fn gen(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("gen")
+        .args(args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn rejects_impossible_ranges() {
+    for (option, value, needs) in [
+        ("--max-duration", "0", "a whole number greater than 0"),
+        ("--max-gap", "-5", "a whole number that is 0 or more"),
+        ("--lambda", "0", "a number greater than 0"),
+        ("--mean-service", "-1", "a number greater than 0"),
+    ] {
+        let stdout = gen(&["3", option, value]);
+        assert_eq!(stdout.lines().next().unwrap(), format!("Error: {option} needs {needs}"));
+    }
+}
+
+// a gap of 0 is allowed, every process then arrives at time 0
+#[test]
+fn zero_gap_is_simultaneous() {
+    let stdout = gen(&["3", "--max-gap", "0"]);
+    assert!(stdout.lines().all(|l| l.starts_with("0 ")), "{stdout}");
+}
+
+// the gaps of a Poisson process average lambda, so over 2000 arrivals the mean gap (the last arrival over the 1999 gaps
+// before it) should be within 5% of it whatever the seed
+#[test]
+fn poisson_gaps_average_lambda() {
+    for lambda in [5.0, 20.0] {
+        for seed in ["1", "2", "3"] {
+            let stdout = gen(&["2000", "--model", "poisson", "--lambda", &lambda.to_string(), "--seed", seed]);
+            let arrivals: Vec<f64> = stdout.lines().map(|l| l.split_whitespace().next().unwrap().parse().unwrap()).collect();
+            assert_eq!(arrivals.len(), 2000);
+            let mean_gap = arrivals.windows(2).map(|w| w[1] - w[0]).sum::<f64>() / 1999.0;
+            assert!((mean_gap - lambda).abs() < 0.05 * lambda, "lambda {lambda} seed {seed}: mean gap {mean_gap}");
+        }
+    }
+}
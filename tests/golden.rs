@@ -0,0 +1,106 @@
+// Golden file regression tests. Every workload in tests/fixtures (*.txt or *.json) has a matching .expected file that stores the
+// average turnaround and response time every algorithm produced on it when the file was written. This test runs the
+// real binary on every fixture with every algorithm and compares what it prints now against those stored values,
+// so any change in scheduling behavior shows up here even if nobody meant to change it.
+//
+// The .expected format is one line per algorithm: "algorithm avg_turnaround avg_response", lines starting with # are
+// comments. To add a fixture, drop a workload in tests/fixtures and run the tests with UPDATE_GOLDEN=1 to write its
+// .expected file (this also rewrites the others, so check the git diff before committing). A fixture can also have an
+// .args file with extra options to run it with, like "--cpus 2".
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// every algorithm the binary knows about
+const ALGORITHMS: [&str; 18] = ["fifo", "sjf", "stcf", "rr", "mlfq", "spn", "priority", "gang", "two-level", "cfs", "edf", "hybrid", "ljf", "aging", "memory", "smith", "rr-classed", "rr-multi"];
+
+// how far apart two metrics can be and still count as equal, they are floats so exact comparison is too strict
+const EPSILON: f32 = 1e-4;
+
+// finds every workload in the fixtures folder, sorted so the failures come out in the same order every run
+fn fixtures() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures");
+    let mut found: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt" || ext == "json"))
+        .collect();
+    found.sort();
+    found
+}
+
+// runs the binary and pulls the two averages out of what it prints
+// input: algorithm name, workload path, output: (average turnaround, average response)
+fn run(algo: &str, workload: &Path) -> (f32, f32) {
+    let extra_args = fs::read_to_string(workload.with_extension("args")).unwrap_or_default();
+    // cargo builds the binary before running integration tests and tells us where it is through this variable
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(extra_args.split_whitespace())
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let metric = |label: &str| -> f32 {
+        let line = stdout.lines().find(|l| l.starts_with(label))
+            .unwrap_or_else(|| panic!("{algo} on {} printed no \"{label}\" line", workload.display()));
+        line[label.len()..].trim().parse().unwrap()
+    };
+    (metric("Average Turnaround Time:"), metric("Average Response Time:"))
+}
+
+// reads a .expected file into (algorithm, average turnaround, average response) tuples
+fn read_expected(path: &Path) -> Vec<(String, f32, f32)> {
+    let text = fs::read_to_string(path)
+        .unwrap_or_else(|_| panic!("{} is missing, run with UPDATE_GOLDEN=1 to create it", path.display()));
+    text.lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|l| {
+            let columns: Vec<&str> = l.split_whitespace().collect();
+            (columns[0].to_string(), columns[1].parse().unwrap(), columns[2].parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn golden_metrics_match() {
+    let update = env::var("UPDATE_GOLDEN").is_ok();
+    let fixtures = fixtures();
+    assert!(fixtures.len() >= 5, "expected at least 5 fixtures, found {}", fixtures.len());
+
+    // collect every mismatch instead of stopping at the first, so one run shows the whole diff
+    let mut failures: Vec<String> = Vec::new();
+    for workload in &fixtures {
+        let expected_path = workload.with_extension("expected");
+
+        if update {
+            let mut text = String::from("# algorithm avg_turnaround avg_response\n");
+            for algo in ALGORITHMS {
+                let (turnaround, response) = run(algo, workload);
+                text.push_str(&format!("{algo} {turnaround} {response}\n"));
+            }
+            fs::write(&expected_path, text).unwrap();
+            continue;
+        }
+
+        let expected = read_expected(&expected_path);
+        for algo in ALGORITHMS {
+            if !expected.iter().any(|(name, _, _)| name == algo) {
+                failures.push(format!("{}: no expected values for {algo}", expected_path.display()));
+            }
+        }
+        for (algo, turnaround, response) in expected {
+            let (got_turnaround, got_response) = run(&algo, workload);
+            if (got_turnaround - turnaround).abs() > EPSILON || (got_response - response).abs() > EPSILON {
+                failures.push(format!(
+                    "{} {algo}: expected turnaround={turnaround} response={response}, got turnaround={got_turnaround} response={got_response}",
+                    workload.file_name().unwrap().to_string_lossy()
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "golden mismatches:\n{}", failures.join("\n"));
+}
@@ -0,0 +1,32 @@
+// Test for hybrid with --window 4 on hybrid.txt, durations 6, 2 and 3 all arriving at 0. The first window (0 to 4) is
+// sjf: pid 1 (2) runs to completion, then pid 2 (3) starts and still has 1 left when the window ends. The second
+// window (4 to 8) is rr, pid 0 and pid 2 take turns a tick at a time until pid 2 completes at 6 and pid 0 has the CPU
+// to itself, and it keeps it into the next sjf window since nothing else is left.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn switches_between_sjf_and_rr() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("hybrid.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("hybrid")
+        .arg(workload)
+        .args(["--window", "4", "--dispatch-order", "--at", "1,3,4,5,6,7"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "Dispatch Order: 1 2 0 2 0"));
+    let running: Vec<&str> = stdout.lines().filter(|l| l.starts_with("At t=")).collect();
+    assert_eq!(running, vec![
+        // sjf window, the shortest runs to completion
+        "At t=1: pid 1",
+        "At t=3: pid 2",
+        // rr window, a tick each
+        "At t=4: pid 0",
+        "At t=5: pid 2",
+        "At t=6: pid 0",
+        "At t=7: pid 0",
+    ]);
+}
@@ -0,0 +1,30 @@
+// Test for --interactive. long_first.txt starts with a duration 50 job and then short jobs arrive behind it. fifo makes
+// every short job wait for the long one, so the worst response is most of its duration, while rr gets each short job
+// onto the CPU within a few ticks of arriving.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on long_first.txt with --interactive and returns the max response time it prints
+fn max_response(algo: &str) -> f32 {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("long_first.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--interactive", "--quantum", "1"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = stdout.lines().find(|l| l.starts_with("Max Response Time:")).unwrap();
+    line.split_whitespace().last().unwrap().parse().unwrap()
+}
+
+#[test]
+fn fifo_has_a_much_higher_max_response() {
+    let fifo = max_response("fifo");
+    let rr = max_response("rr");
+    assert_eq!(fifo, 51.0);
+    assert_eq!(rr, 4.0);
+    assert!(fifo > 10.0 * rr);
+}
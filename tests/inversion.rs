@@ -0,0 +1,35 @@
+// Tests for --inversion. Two processes arrive together, pid 1 with the higher priority, and rr takes turns between
+// them a tick at a time, so every tick pid 0 runs is a tick pid 1 waits behind it. Run to completion that is all 4 of
+// pid 0's ticks. Stopped at 2 with --stop-at neither has completed, and the one tick pid 0 ran still counts, with the
+// higher priority pid 1 waiting even though it never completed.
+
+use std::env;
+use std::process::Command;
+
+// runs rr with --inversion and the arguments on the workload and returns the inversion time it prints
+fn inversion(args: &[&str]) -> f32 {
+    let path = env::temp_dir().join(format!("final_scheduler_inversion_{}_{}.txt", std::process::id(), args.len()));
+    std::fs::write(&path, "0 4 priority=1\n0 4 priority=0\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(&path)
+        .arg("--inversion")
+        .args(args)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find_map(|l| l.strip_prefix("Priority Inversion Time:")).unwrap().trim().parse().unwrap()
+}
+
+#[test]
+fn every_tick_of_the_lower_priority() {
+    assert_eq!(inversion(&[]), 4.0);
+}
+
+#[test]
+fn counts_processes_that_never_completed() {
+    let state = env::temp_dir().join(format!("final_scheduler_inversion_state_{}.txt", std::process::id()));
+    assert_eq!(inversion(&["--checkpoint", state.to_str().unwrap(), "--stop-at", "2"]), 1.0);
+    std::fs::remove_file(&state).unwrap();
+}
@@ -0,0 +1,50 @@
+// Tests for "gen --jitter". The jitter is drawn after the nominal schedule, so the same seed with and without it gives
+// the same processes with only the arrivals moved. Moving every arrival by at most the jitter moves the k-th smallest
+// arrival by at most the jitter too, so after sorting both, each jittered arrival has to be within the bound of the
+// nominal one at the same position, and the jittered output itself has to come out sorted.
+
+use std::process::Command;
+
+// runs gen with the given extra options and returns (arrival, duration) for every line it prints
+fn generate(extra: &[&str]) -> Vec<(i32, i32)> {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .args(["gen", "200", "--seed", "42", "--max-gap", "2"])
+        .args(extra)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap().lines().map(|l| {
+        let mut cols = l.split_whitespace().map(|c| c.parse().unwrap());
+        (cols.next().unwrap(), cols.next().unwrap())
+    }).collect()
+}
+
+#[test]
+fn arrivals_stay_within_the_bound() {
+    let nominal = generate(&[]);
+    let jittered = generate(&["--jitter", "3"]);
+    assert_eq!(jittered.len(), nominal.len());
+    assert!(jittered.windows(2).all(|w| w[0].0 <= w[1].0), "jittered output is not sorted by arrival");
+    assert!(jittered.iter().all(|&(arrival, _)| arrival >= 0));
+    let mut nominal_arrivals: Vec<i32> = nominal.iter().map(|p| p.0).collect();
+    nominal_arrivals.sort();
+    for (j, n) in jittered.iter().map(|p| p.0).zip(nominal_arrivals) {
+        assert!((j - n).abs() <= 3, "arrival {j} is more than 3 from {n}");
+    }
+    assert_ne!(jittered, nominal, "the jitter did not move anything");
+    // only arrivals move, the same durations are all still there
+    let mut nominal_durations: Vec<i32> = nominal.iter().map(|p| p.1).collect();
+    let mut jittered_durations: Vec<i32> = jittered.iter().map(|p| p.1).collect();
+    nominal_durations.sort();
+    jittered_durations.sort();
+    assert_eq!(jittered_durations, nominal_durations);
+}
+
+#[test]
+fn same_seed_same_jitter() {
+    assert_eq!(generate(&["--jitter", "3"]), generate(&["--jitter", "3"]));
+}
+
+#[test]
+fn zero_jitter_is_the_nominal_schedule() {
+    assert_eq!(generate(&["--jitter", "0"]), generate(&[]));
+}
@@ -0,0 +1,19 @@
+// Test for --format line. fifo on staggered.txt runs the 4 processes in arrival order, completing at 8, 12, 21 and 26,
+// so the average turnaround is 15.25 and the average response 8.75, and that one line is all it prints.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn one_summary_line() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .args(["--format", "line"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout, "algo=fifo workload=staggered.txt n=4 avg_tt=15.25 avg_rt=8.75\n");
+}
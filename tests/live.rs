@@ -0,0 +1,46 @@
+// Test for --live: processes written to a named pipe while rr runs should be scheduled exactly like they would be if
+// they had been in the workload file from the start. rr is started on staggered.txt with the pipe, two arrivals are
+// written to it (one during the workload, one after it has finished) and then it is closed, and the processes rr
+// prints are compared against rr on a copy of staggered.txt with the two lines added.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+const LIVE: &str = "5 2\n30 1\n";
+
+// the "pid=..." lines of a run
+fn processes(stdout: &[u8]) -> Vec<String> {
+    String::from_utf8(stdout.to_vec()).unwrap().lines().filter(|l| l.trim().starts_with("pid=")).map(|l| l.trim().to_string()).collect()
+}
+
+#[test]
+fn matches_the_combined_workload() {
+    let staggered = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let pipe = env::temp_dir().join(format!("final_scheduler_live_{}", std::process::id()));
+    let _ = fs::remove_file(&pipe);
+    assert!(Command::new("mkfifo").arg(&pipe).status().unwrap().success());
+    let child = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(&staggered)
+        .arg("--live")
+        .arg(&pipe)
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // opening the write end waits for rr to open the read end, and dropping it closes the pipe
+    fs::OpenOptions::new().write(true).open(&pipe).unwrap().write_all(LIVE.as_bytes()).unwrap();
+    let live = child.wait_with_output().unwrap();
+    fs::remove_file(&pipe).unwrap();
+
+    let combined = env::temp_dir().join(format!("final_scheduler_live_combined_{}.txt", std::process::id()));
+    fs::write(&combined, fs::read_to_string(&staggered).unwrap() + LIVE).unwrap();
+    let offline = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("rr").arg(&combined).output().unwrap();
+    fs::remove_file(&combined).unwrap();
+
+    let expected = processes(&offline.stdout);
+    assert_eq!(expected.len(), 6);
+    assert_eq!(processes(&live.stdout), expected);
+}
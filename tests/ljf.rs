@@ -0,0 +1,24 @@
+// Test for ljf against sjf on mixed_40.txt. Both run every process to completion and only differ in which ready
+// process they pick, and picking the longest makes every short job wait behind the long ones, so ljf's average
+// turnaround has to be higher.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on mixed_40.txt and returns its average turnaround
+fn avg_turnaround(algo: &str) -> f32 {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("mixed_40.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find_map(|l| l.strip_prefix("Average Turnaround Time:")).unwrap().trim().parse().unwrap()
+}
+
+#[test]
+fn ljf_turnaround_is_worse_than_sjf() {
+    assert!(avg_turnaround("ljf") > avg_turnaround("sjf"));
+}
@@ -0,0 +1,36 @@
+// Tests for makespan. On a simple fifo run the makespan printed has to be the last completion minus the first arrival,
+// worked out here from the process lines. late_start.txt is there because its first arrival is far from 0, so a
+// makespan that forgot to subtract it would be off by 16 million.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on the fixture and returns (last completion - first arrival from the process lines, printed makespan)
+fn makespans(fixture: &str) -> (f32, f32) {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let field = |line: &str, name: &str| -> f32 {
+        line.split(", ").find_map(|f| f.strip_prefix(name)).unwrap().parse().unwrap()
+    };
+    let lines: Vec<&str> = stdout.lines().filter(|l| l.starts_with("\tpid=")).collect();
+    let first_arrival = lines.iter().map(|l| field(l, "arrival=")).fold(f32::MAX, f32::min);
+    let last_completion = lines.iter().map(|l| field(l, "completion=")).fold(f32::MIN, f32::max);
+    let printed = stdout.lines().find_map(|l| l.strip_prefix("Makespan:")).unwrap().trim().parse().unwrap();
+    (last_completion - first_arrival, printed)
+}
+
+#[test]
+fn starts_at_zero() {
+    assert_eq!(makespans("staggered.txt"), (26.0, 26.0));
+}
+
+#[test]
+fn starts_late() {
+    assert_eq!(makespans("late_start.txt"), (12.0, 12.0));
+}
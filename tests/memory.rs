@@ -0,0 +1,47 @@
+// Test for the memory algorithm on memory.txt, run with --total-memory 10. pid 0 (memory 2) runs first and pid 1
+// (memory 6) is admitted when it arrives, which leaves 2 free, so pid 2 (memory 5) has to wait even though it is the
+// shortest. When pid 0 completes only 4 is free, still not enough, so pid 1 runs next (sjf would have picked pid 2 at
+// t=4) and pid 2 only gets in once pid 1 completes at t=10.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs an algorithm on memory.txt and returns the first_run of every process, in pid order
+fn first_runs(algo: &str) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("memory.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--total-memory", "10"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut lines: Vec<&str> = stdout.lines().filter(|l| l.starts_with("\tpid=")).collect();
+    lines.sort();
+    lines.iter().map(|l| l.split(", ").find(|c| c.starts_with("first_run=")).unwrap().to_string()).collect()
+}
+
+#[test]
+fn large_memory_waits_for_memory() {
+    assert_eq!(first_runs("memory"), vec!["first_run=0", "first_run=4", "first_run=10"]);
+}
+
+#[test]
+fn sjf_ignores_memory() {
+    assert_eq!(first_runs("sjf"), vec!["first_run=0", "first_run=5", "first_run=4"]);
+}
+
+// a process that needs more than the total memory never runs
+#[test]
+fn too_big_is_incomplete() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("memory.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("memory")
+        .arg(workload)
+        .args(["--total-memory", "5"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "1 processes incomplete (not counted in the averages above):"), "{stdout}");
+}
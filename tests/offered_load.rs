@@ -0,0 +1,46 @@
+// Tests for --load. Five processes arriving every 2 ticks from 0 to 8 with a duration of 1 are an arrival rate of
+// 5 / 8 and a mean service time of 1, so rho is 0.625 and there's no warning. Four processes of duration 2 arriving at
+// 0, 1, 2 and 3 are a rate of 4 / 3 and rho 8 / 3, so the warning is printed.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+// writes the workload to a temporary file, runs fifo on it with --load and returns what it prints
+fn run(name: &str, workload: &str) -> String {
+    let path = env::temp_dir().join(format!("final_scheduler_load_{name}_{}.txt", std::process::id()));
+    fs::write(&path, workload).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(&path)
+        .arg("--load")
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// the number after the label, None if it wasn't printed
+fn rho(stdout: &str) -> Option<f32> {
+    stdout.lines().find_map(|l| l.strip_prefix("Offered Load (rho):")).and_then(|v| v.trim().parse().ok())
+}
+
+#[test]
+fn underloaded() {
+    let stdout = run("under", "0 1\n2 1\n4 1\n6 1\n8 1\n");
+    assert!((rho(&stdout).unwrap() - 0.625).abs() < 0.0001, "{stdout}");
+    assert!(!stdout.contains("Warning: rho"));
+}
+
+#[test]
+fn overloaded() {
+    let stdout = run("over", "0 2\n1 2\n2 2\n3 2\n");
+    assert!((rho(&stdout).unwrap() - 8.0 / 3.0).abs() < 0.0001, "{stdout}");
+    assert!(stdout.lines().any(|l| l.starts_with("Warning: rho is at least 1")));
+}
+
+#[test]
+fn all_at_once() {
+    let stdout = run("once", "0 2\n0 3\n");
+    assert!(stdout.lines().any(|l| l == "Offered Load (rho):      undefined (every process arrives at the same time)"));
+}
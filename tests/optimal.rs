@@ -0,0 +1,46 @@
+// Tests for --compare-optimal. When every process arrives at 0 optimal_offline is shortest first from t=0, which is
+// what sjf does as long as the first line is also the shortest (sjf starts the first line before looking at the rest),
+// and what stcf does whatever the order. No algorithm can beat the optimum on these workloads.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// runs --compare-optimal on a workload and returns (algorithm, vs optimal) for every row of the table
+fn rows(workload: &Path) -> Vec<(String, String)> {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(workload)
+        .arg("--compare-optimal")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // the optimum, then the header, then the rows
+    stdout.lines().skip(2).map(|l| {
+        let columns: Vec<&str> = l.split_whitespace().collect();
+        (columns[0].to_string(), columns[2].to_string())
+    }).collect()
+}
+
+fn vs_optimal<'a>(rows: &'a [(String, String)], algo: &str) -> &'a str {
+    &rows.iter().find(|(name, _)| name == algo).unwrap().1
+}
+
+#[test]
+fn sjf_is_optimal_when_everything_arrives_at_once() {
+    let path = env::temp_dir().join(format!("final_scheduler_optimal_{}.txt", std::process::id()));
+    fs::write(&path, "0 2\n0 9\n0 4\n0 6\n").unwrap();
+    let rows = rows(&path);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(vs_optimal(&rows, "sjf"), "+0.0%");
+    assert_eq!(vs_optimal(&rows, "stcf"), "+0.0%");
+}
+
+#[test]
+fn nothing_beats_it() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("simultaneous.txt");
+    let rows = rows(&workload);
+    assert_eq!(rows.len(), 18);
+    assert_eq!(vs_optimal(&rows, "stcf"), "+0.0%");
+    assert!(rows.iter().all(|(_, difference)| difference.starts_with('+')), "{rows:?}");
+}
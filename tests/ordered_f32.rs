@@ -0,0 +1,15 @@
+// Test for OrderedF32. Workload files only have whole number durations, so selftest builds a workload with a NaN
+// duration and runs optimal_offline on it, which sorts by duration. It used to panic on the NaN, now it has to run the
+// NaN process after the others, and this checks that it passed.
+
+use std::process::Command;
+
+#[test]
+fn nan_duration_sorts_last() {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("selftest")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "OrderedF32 (a NaN duration sorts last): PASS"), "{stdout}");
+}
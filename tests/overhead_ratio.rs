@@ -0,0 +1,96 @@
+// Tests for the overhead ratio printed with --dispatch-overhead. dispatch_overhead.txt has 4 processes of 8, 4, 9 and 5
+// arriving one after another, and every rr time slice is a dispatch costing 0.5. With a quantum of 1 that is 26 slices,
+// 13 of overhead in a makespan of 26 + 13 = 39. With a quantum of 2 it is 4 + 2 + 5 + 3 = 14 slices, 7 in 33.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr with the quantum and a dispatch overhead of 0.5 and returns the overhead line
+fn overhead_line(quantum: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("dispatch_overhead.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .args(["--dispatch-overhead", "0.5", "--quantum", quantum])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("Overhead Ratio:")).unwrap().to_string()
+}
+
+// the ratio at the start of the line
+fn ratio(line: &str) -> f32 {
+    line.split_whitespace().nth(2).unwrap().parse().unwrap()
+}
+
+#[test]
+fn every_slice_is_a_dispatch() {
+    let line = overhead_line("1");
+    assert!(line.ends_with("(13 of the makespan 39 was dispatch overhead)"), "{line}");
+    assert!((ratio(&line) - 1.0 / 3.0).abs() < 1e-6);
+
+    let line = overhead_line("2");
+    assert!(line.ends_with("(7 of the makespan 33 was dispatch overhead)"), "{line}");
+    assert!((ratio(&line) - 7.0 / 33.0).abs() < 1e-6);
+}
+
+// runs the algorithm with a dispatch overhead of 0.5 and --check-conservation and returns the overhead line, after
+// checking the busy time still adds up
+fn other_overhead_line(algo: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("dispatch_overhead.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--dispatch-overhead", "0.5", "--check-conservation"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "Conservation: OK"), "{algo}: {stdout}");
+    stdout.lines().find(|l| l.starts_with("Overhead Ratio:")).unwrap().to_string()
+}
+
+// two-level runs 1 tick slices over these processes, the same 26 dispatches as rr with a quantum of 1
+#[test]
+fn two_level_charges_every_slice() {
+    assert!(other_overhead_line("two-level").ends_with("(13 of the makespan 39 was dispatch overhead)"));
+}
+
+#[test]
+fn nothing_without_overhead() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("dispatch_overhead.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("rr").arg(workload).output().unwrap();
+    assert!(!String::from_utf8(output.stdout).unwrap().contains("Overhead Ratio:"));
+}
+
+// cfs picks 15 times here, pid 0 twice at the start because pid 1's arrival at 1 makes it pick again and pid 0 wins
+// the tie on vruntime, and every pick is a dispatch, 7.5 of overhead
+#[test]
+fn cfs_charges_every_pick() {
+    assert!(other_overhead_line("cfs").ends_with("(7.5 of the makespan 33.5 was dispatch overhead)"));
+}
+
+// none of these processes has a deadline, so edf runs them first come first served and like stcf only a different
+// process taking the CPU is a dispatch, 4 of them
+#[test]
+fn edf_charges_only_a_new_process() {
+    assert!(other_overhead_line("edf").ends_with("(2 of the makespan 28 was dispatch overhead)"));
+}
+
+// hybrid charges every pick from the ready set, once for each process SJF runs and every tick in a RR window
+#[test]
+fn hybrid_charges_every_pick() {
+    assert!(other_overhead_line("hybrid").ends_with("(7 of the makespan 33 was dispatch overhead)"));
+}
+
+// every process has the same priority, so aging keeps handing the CPU to whichever has waited longest, 24 times
+#[test]
+fn aging_charges_only_a_new_process() {
+    assert!(other_overhead_line("aging").ends_with("(12 of the makespan 38 was dispatch overhead)"));
+}
+
+// every process is in class 0, so rr-classed is rr with the default quantum of 1, the same 26 slices
+#[test]
+fn rr_classed_charges_every_slice() {
+    assert!(other_overhead_line("rr-classed").ends_with("(13 of the makespan 39 was dispatch overhead)"));
+}
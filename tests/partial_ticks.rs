@@ -0,0 +1,37 @@
+// Tests for --partial-ticks on half_tick.json, a process of duration 2.5 and one of duration 1 that both arrive at 0.
+// Whichever order stcf, rr and mlfq run them in the CPU is busy for exactly 3.5, so the long process should complete
+// at 3.5 instead of overshooting to 4 at the end of its last tick (and the run has to finish at all).
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs an algorithm on half_tick.json with the extra arguments and returns pid 0's line
+fn long_process(algo: &str, extra: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("half_tick.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--quiet")
+        .args(extra)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("\tpid=0,")).unwrap().to_string()
+}
+
+#[test]
+fn completes_at_the_fractional_time() {
+    for algo in ["stcf", "rr", "mlfq"] {
+        let line = long_process(algo, &["--partial-ticks"]);
+        assert!(line.contains("completion=3.5,"), "{algo}: {line}");
+    }
+}
+
+#[test]
+fn overshoots_without_it() {
+    for algo in ["stcf", "rr", "mlfq"] {
+        let line = long_process(algo, &[]);
+        assert!(line.contains("completion=4,"), "{algo}: {line}");
+    }
+}
@@ -0,0 +1,29 @@
+// Test for --peak-footprint: a burst of N processes arriving together at 0, and one more arriving long after the burst
+// has finished. Each preemptive algorithm has to hold the whole burst at t=0, before anything has had the time to
+// complete, and never more than that, so the peak should be N and be reached at 0.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+const N: usize = 6;
+
+#[test]
+fn burst_is_the_peak() {
+    let mut lines: Vec<String> = (0..N).map(|i| format!("0 {}", i + 2)).collect();
+    lines.push("100 3".to_string());
+    let workload = env::temp_dir().join(format!("final_scheduler_peak_footprint_{}.txt", std::process::id()));
+    fs::write(&workload, lines.join("\n") + "\n").unwrap();
+    for algo in ["stcf", "rr", "mlfq"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+            .arg(algo)
+            .arg(&workload)
+            .args(["--peak-footprint", "--quiet"])
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let line = stdout.lines().find_map(|l| l.strip_prefix("Peak Footprint:")).unwrap();
+        assert_eq!(line.trim(), format!("{N} (at t=0)"), "{algo}");
+    }
+    fs::remove_file(workload).unwrap();
+}
@@ -0,0 +1,47 @@
+// Tests for --periodic. periodic.txt has a task with period 4 and one with period 6, both first arriving at 0, so the
+// hyperperiod is 12 and they have 3 and 2 jobs in it. A task with its own deadline keeps it instead of being due at
+// its next arrival.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn jobs_over_the_hyperperiod() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("periodic.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("edf")
+        .arg(workload)
+        .arg("--periodic")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().next().unwrap(), "Expanded 2 periodic tasks into 5 jobs over the hyperperiod 12");
+    // every job is scheduled, at the arrivals 0, 4, 8 and 0, 6
+    let mut arrivals: Vec<&str> = stdout.lines()
+        .filter_map(|l| l.trim().strip_prefix("pid="))
+        .map(|l| l.split(", ").nth(1).unwrap())
+        .collect();
+    arrivals.sort();
+    assert_eq!(arrivals, vec!["arrival=0", "arrival=0", "arrival=4", "arrival=6", "arrival=8"]);
+    assert!(stdout.lines().any(|l| l == "\ttask 0: 0 of 3 jobs missed (0%)"), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "\ttask 1: 0 of 2 jobs missed (0%)"), "{stdout}");
+}
+
+// the period 4 task has a deadline of 1, which its duration 2 job can't make, while being due at its next arrival (4)
+// it would have made it. edf runs it first for its earlier deadline, so the period 2 job at 0 misses too
+#[test]
+fn task_keeps_its_own_deadline() {
+    let path = env::temp_dir().join(format!("final_scheduler_periodic_deadline_{}.txt", std::process::id()));
+    std::fs::write(&path, "0 2 period=4 deadline=1\n0 1 period=2\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("edf")
+        .arg(&path)
+        .args(["--periodic", "--deadlines"])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "\tpid=0 missed its soft deadline 1 by 1"), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "\ttask 0: 1 of 1 jobs missed (100%)"), "{stdout}");
+}
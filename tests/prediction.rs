@@ -0,0 +1,30 @@
+// Tests for spn's prediction accuracy. spn guesses each CPU burst by exponential averaging (alpha 0.5, a first guess
+// of 5), so bursts that stay the same are predicted almost exactly and bursts that jump around are not. The steady
+// workload is 5,5,5 (every guess right) and 4,4,4 (guesses 5, 4.5, 4.25), an average error of 1.75 / 6. The erratic
+// one is 1,13,1 (guesses 5, 3, 8) and 10,1,1 (guesses 5, 7.5, 4.25), 35.75 / 6.
+
+use std::env;
+use std::process::Command;
+
+// runs spn on a workload of the lines and returns the average prediction error it prints
+fn prediction_error(name: &str, lines: &str) -> f32 {
+    let path = env::temp_dir().join(format!("final_scheduler_prediction_{name}_{}.txt", std::process::id()));
+    std::fs::write(&path, lines).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("spn")
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find_map(|l| l.strip_prefix("Average Prediction Error:")).unwrap().trim().parse().unwrap()
+}
+
+#[test]
+fn steady_bursts_predict_better_than_erratic_ones() {
+    let steady = prediction_error("steady", "0 15 bursts=5,5,5\n0 12 bursts=4,4,4\n");
+    let erratic = prediction_error("erratic", "0 15 bursts=1,13,1\n0 12 bursts=10,1,1\n");
+    assert!((steady - 1.75 / 6.0).abs() < 1e-5, "{steady}");
+    assert!((erratic - 35.75 / 6.0).abs() < 1e-5, "{erratic}");
+    assert!(steady < erratic);
+}
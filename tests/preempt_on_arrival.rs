@@ -0,0 +1,36 @@
+// Tests for --preempt-on-arrival. In arrival_mid_tick.json a process of duration 1 arrives at t=2.5 while a process of
+// duration 5 is running. stcf normally only sees it at the end of the tick, at 3, with --preempt-on-arrival it takes the
+// CPU at exactly 2.5 and completes at 3.5, and the long process still completes at 6 since no CPU time is wasted.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs stcf on arrival_mid_tick.json with the extra arguments and returns the process lines it prints
+fn processes(extra: &[&str]) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("arrival_mid_tick.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("stcf")
+        .arg(workload)
+        .args(extra)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().filter(|l| l.starts_with("\tpid=")).map(|l| l.trim().to_string()).collect()
+}
+
+#[test]
+fn preempts_at_the_arrival() {
+    let lines = processes(&["--preempt-on-arrival"]);
+    let short = lines.iter().find(|l| l.starts_with("pid=1,")).unwrap();
+    assert!(short.contains("first_run=2.5, completion=3.5"), "{short}");
+    let long = lines.iter().find(|l| l.starts_with("pid=0,")).unwrap();
+    assert!(long.contains("completion=6,"), "{long}");
+}
+
+#[test]
+fn waits_for_the_tick_without_it() {
+    let lines = processes(&[]);
+    let short = lines.iter().find(|l| l.starts_with("pid=1,")).unwrap();
+    assert!(short.contains("first_run=3, completion=4"), "{short}");
+}
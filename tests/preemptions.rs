@@ -0,0 +1,36 @@
+// Test for --preemptions. In long_and_short.txt a duration 8 job arrives with a duration 2 one, and two more short
+// ones arrive right after. rr keeps taking the long job off the CPU for them, so it is preempted the most (4 times),
+// while fifo runs everything to completion and never preempts anything.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on long_and_short.txt with --preemptions and returns the lines it prints about them
+fn preemptions(algo: &str) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("long_and_short.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--preemptions")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().skip_while(|l| *l != "Preemptions:").skip(1).map(|l| l.trim().to_string()).collect()
+}
+
+#[test]
+fn rr_preempts_the_long_job_most() {
+    assert_eq!(preemptions("rr"), vec![
+        "pid=0, preemptions=4",
+        "pid=1, preemptions=1",
+        "pid=2, preemptions=2",
+        "pid=3, preemptions=1",
+        "Most Preemptions:        4 (pid 0)",
+    ]);
+}
+
+#[test]
+fn fifo_never_preempts() {
+    assert_eq!(preemptions("fifo").last().unwrap(), "Most Preemptions:        0 (pid 0)");
+}
@@ -0,0 +1,35 @@
+// Tests for --at, which looks up the process on the CPU at a time in the run segments. Under fifo idle_gap.txt runs
+// pid 0 from 0 to 3, leaves the CPU idle until 5, then runs pid 1 until 7. staggered.txt has pid 0 hand the CPU
+// straight to pid 1 at 8.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on a fixture with --at and returns the "At t=..." lines
+fn at(fixture: &str, times: &str) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .args(["--at", times])
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap().lines().filter(|l| l.starts_with("At t=")).map(|l| l.to_string()).collect()
+}
+
+#[test]
+fn running_and_idle() {
+    assert_eq!(at("idle_gap.txt", "1.5,4,5,7"), vec![
+        "At t=1.5: pid 0",
+        "At t=4: idle",
+        "At t=5: pid 1",
+        // pid 1 completes at 7 and nothing comes after it
+        "At t=7: idle",
+    ]);
+}
+
+#[test]
+fn boundary_is_the_process_starting() {
+    assert_eq!(at("staggered.txt", "7.9,8"), vec!["At t=7.9: pid 0", "At t=8: pid 1"]);
+}
@@ -0,0 +1,37 @@
+// Test for the processor sharing baseline printed by --vs-ps. Under processor sharing two duration 4 jobs that arrive
+// together each get half the CPU the whole time, so both complete at 8.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr on a fixture with --vs-ps and returns the (pid, completion, ps_completion) rows it prints
+fn ps_rows(fixture: &str) -> Vec<(String, f32, f32)> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .arg("--vs-ps")
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+        .lines()
+        .skip_while(|l| !l.starts_with("Turnaround vs PS:"))
+        .skip(1)
+        .take_while(|l| l.starts_with('\t'))
+        .map(|l| {
+            let values: Vec<&str> = l.trim().split(", ").map(|kv| kv.split('=').nth(1).unwrap()).collect();
+            (values[0].to_string(), values[1].parse().unwrap(), values[2].parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn equal_jobs_complete_together() {
+    let rows = ps_rows("equal_pair.txt");
+    assert_eq!(rows.len(), 2);
+    for (pid, _, ps_completion) in rows {
+        assert_eq!(ps_completion, 8.0, "pid {pid}");
+    }
+}
+
@@ -0,0 +1,43 @@
+// Tests for --queue-stats. When several processes arrive at the same tick every one of them is in the ready set
+// together until the first one completes, so the peak queue length has to be exactly the size of the burst, whichever
+// tick based algorithm is running.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// every algorithm that tracks the queue length
+const ALGORITHMS: [&str; 3] = ["stcf", "rr", "mlfq"];
+
+// runs the algorithm on the workload with --queue-stats and returns the peak queue length it prints
+fn peak(algo: &str, workload: &PathBuf) -> usize {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--queue-stats")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = stdout.lines().find(|l| l.starts_with("Peak Queue Length:")).unwrap();
+    line.split_whitespace().last().unwrap().parse().unwrap()
+}
+
+// simultaneous.txt is 4 processes all arriving at 0
+#[test]
+fn burst_at_the_start() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("simultaneous.txt");
+    for algo in ALGORITHMS {
+        assert_eq!(peak(algo, &workload), 4, "{algo}");
+    }
+}
+
+// one process that is done by 2, then a burst of 3 at 5 after the CPU went idle
+#[test]
+fn burst_after_an_idle_gap() {
+    let workload = env::temp_dir().join(format!("final_scheduler_queue_stats_{}.txt", std::process::id()));
+    std::fs::write(&workload, "0 2\n5 3\n5 3\n5 3\n").unwrap();
+    for algo in ALGORITHMS {
+        assert_eq!(peak(algo, &workload), 3, "{algo}");
+    }
+    std::fs::remove_file(&workload).unwrap();
+}
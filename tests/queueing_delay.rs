@@ -0,0 +1,27 @@
+// Test for --queueing-delay against queueing theory. gen --model md1 makes an M/D/1 queue: Poisson arrivals with a
+// mean gap of 8 (rate 1/8) and every duration 4, so the CPU is busy half the time (rho = 0.5). For M/D/1 the average
+// time spent waiting is W_q = rate * D^2 / (2 * (1 - rho)) = 2, and fifo on a long enough workload should land close
+// to it. The arrivals are rounded to whole numbers and the workload is finite, so it is only checked within 5%.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+// runs the binary with the arguments and returns what it prints
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).args(args).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn fifo_matches_md1() {
+    let path = env::temp_dir().join(format!("final_scheduler_md1_{}.txt", std::process::id()));
+    let workload = run(&["gen", "50000", "--model", "md1", "--lambda", "8", "--mean-service", "4"]);
+    fs::write(&path, workload).unwrap();
+    let stdout = run(&["fifo", path.to_str().unwrap(), "--queueing-delay", "--format", "line"]);
+    fs::remove_file(&path).unwrap();
+
+    let delay: f32 = stdout.lines().find_map(|l| l.strip_prefix("Average Queueing Delay:")).unwrap().trim().parse().unwrap();
+    let expected = (1.0 / 8.0) * 4.0 * 4.0 / (2.0 * (1.0 - 0.5));
+    assert!((delay - expected).abs() < expected * 0.05, "queueing delay {delay}, M/D/1 says {expected}");
+}
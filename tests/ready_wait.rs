@@ -0,0 +1,24 @@
+// Test for the initial_wait / ready_wait split. In this workload rr with a quantum of 2 runs pid 0 to completion, then
+// pid 1 (which arrived at 1) for one slice, then pid 2, then pid 1 again. So pid 1 is preempted once: it waited 1
+// before it first ran, and 2 more (while pid 2 had its slice) after that.
+
+use std::env;
+use std::process::Command;
+
+#[test]
+fn preempted_once() {
+    let path = env::temp_dir().join(format!("final_scheduler_ready_wait_{}.txt", std::process::id()));
+    std::fs::write(&path, "0 2\n1 4\n2 2\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(&path)
+        .args(["--quantum", "2", "--dispatch-order"])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "Dispatch Order: 0 1 2 1"), "{stdout}");
+    let pid1 = stdout.lines().find(|l| l.trim().starts_with("pid=1,")).unwrap();
+    assert!(pid1.contains("first_run=2,"), "{pid1}");
+    assert!(pid1.ends_with("initial_wait=1, ready_wait=2"), "{pid1}");
+}
@@ -0,0 +1,40 @@
+// Test for --relative. It should only change how first_run and completion are printed, so every process line of the
+// relative output should be the absolute one with both shifted back by that process's arrival.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr on staggered.txt and returns every process line as (pid, arrival, first_run, completion)
+fn process_lines(extra_args: &[&str]) -> Vec<(String, f32, f32, f32)> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .arg("--quiet")
+        .args(extra_args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+        .lines()
+        .filter(|l| l.trim().starts_with("pid="))
+        .map(|l| {
+            let value = |key: &str| l.trim().split(", ").find_map(|kv| kv.strip_prefix(key)).unwrap().to_string();
+            (value("pid="), value("arrival=").parse().unwrap(), value("first_run=").parse().unwrap(), value("completion=").parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn relative_is_shifted_by_arrival() {
+    let absolute = process_lines(&[]);
+    let relative = process_lines(&["--relative"]);
+    assert_eq!(absolute.len(), 4);
+    assert_eq!(absolute.len(), relative.len());
+    for (a, r) in absolute.iter().zip(&relative) {
+        assert_eq!(a.0, r.0);
+        assert_eq!(a.1, r.1);
+        assert_eq!(a.2 - a.1, r.2, "first_run of pid {}", a.0);
+        assert_eq!(a.3 - a.1, r.3, "completion of pid {}", a.0);
+    }
+}
@@ -0,0 +1,35 @@
+// Tests for --replay-gantt. The chart stcf prints with --gantt-json on mixed_40.txt, read back with --replay-gantt on the
+// same run, has to give back exactly the first_run and completion stcf reported for every process. Moving the end of
+// the last bar is a chart that doesn't match, and the process it belongs to has to be reported.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// runs stcf on mixed_40.txt with the arguments and returns what it prints
+fn stcf(args: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("mixed_40.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("stcf").arg(workload).args(args).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn chart_gives_back_the_metrics() {
+    let chart = stcf(&["--gantt-json"]).lines().last().unwrap().to_string();
+    let path = env::temp_dir().join(format!("final_scheduler_replay_{}.json", std::process::id()));
+    fs::write(&path, &chart).unwrap();
+    let stdout = stcf(&["--replay-gantt", path.to_str().unwrap()]);
+    assert!(stdout.lines().any(|l| l == "Gantt Replay: matches, every first_run and completion is the same"), "{stdout}");
+
+    // the last bar is pid 24 running from 421 to 446
+    assert!(chart.ends_with("{\"pid\": 24, \"start\": 421, \"end\": 446}]"));
+    fs::write(&path, chart.replace("\"end\": 446}]", "\"end\": 447}]")).unwrap();
+    let stdout = stcf(&["--replay-gantt", path.to_str().unwrap()]);
+    let replay: Vec<&str> = stdout.lines().skip_while(|l| !l.starts_with("Gantt Replay:")).collect();
+    assert_eq!(replay, vec![
+        "Gantt Replay: 1 processes differ",
+        "\tpid 24: the chart has first_run=421, completion=447, the run has first_run=421, completion=446",
+    ]);
+    fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,15 @@
+// Test for Process::reset. A schedule that already ran has first_run, completion and remaining_time filled in, and a
+// workload file can't hold those, so selftest feeds fifo's own output back into fifo and checks it gets exactly the
+// same schedule again instead of building on the stale values. This checks that it passed.
+
+use std::process::Command;
+
+#[test]
+fn finished_schedule_reschedules_from_scratch() {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("selftest")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "Process::reset (a finished schedule run through fifo again): PASS"), "{stdout}");
+}
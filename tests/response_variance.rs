@@ -0,0 +1,31 @@
+// Test for --response-variance on equal_response.txt, two processes at 0 and one at 1, all 1 long. rr runs them in
+// arrival order, so the responses are 0, 1 and 1. mlfq puts every arrival at the front of the top level, so pid 1 runs
+// first, the arrival at 1 runs right away and pid 0 waits until 2, the responses 0, 0 and 2. Both average 2/3, but
+// rr's variance is 2/9 and mlfq's 8/9.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on equal_response.txt and returns (average response, response variance)
+fn response(algo: &str) -> (f32, f32) {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("equal_response.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--response-variance")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let metric = |name: &str| -> f32 { stdout.lines().find_map(|l| l.strip_prefix(name)).unwrap().trim().parse().unwrap() };
+    (metric("Average Response Time:"), metric("Response Time Variance:"))
+}
+
+#[test]
+fn same_mean_different_variance() {
+    let (rr_mean, rr_variance) = response("rr");
+    let (mlfq_mean, mlfq_variance) = response("mlfq");
+    assert!((rr_mean - 2.0 / 3.0).abs() < 1e-5 && (mlfq_mean - 2.0 / 3.0).abs() < 1e-5);
+    assert!((rr_variance - 2.0 / 9.0).abs() < 1e-5, "{rr_variance}");
+    assert!((mlfq_variance - 8.0 / 9.0).abs() < 1e-5, "{mlfq_variance}");
+}
@@ -0,0 +1,27 @@
+// Test for rr-classed on classes.txt. pid 0 is in class 1 and arrives at 0 with pid 1, pid 2 is in class 0 and arrives
+// at 1. Class 0 always has something ready until pids 1 and 2 complete at 5 and 6 (they take turns, like rr), so pid 0
+// only gets the CPU at 6 even though it arrived first.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn lower_class_waits_for_higher_classes() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("classes.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr-classed")
+        .arg(workload)
+        .arg("--dispatch-order")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l == "Dispatch Order: 1 2 1 2 1 2 0"), "{stdout}");
+    let field = |pid: usize, name: &str| -> f32 {
+        let line = stdout.lines().find(|l| l.starts_with(&format!("\tpid={pid},"))).unwrap();
+        let value = line.split(", ").find_map(|c| c.strip_prefix(&format!("{name}="))).unwrap();
+        value.parse().unwrap()
+    };
+    let last_higher = field(1, "completion").max(field(2, "completion"));
+    assert_eq!(field(0, "first_run"), last_higher);
+}
@@ -0,0 +1,27 @@
+// Test for --running-average. stcf on staggered.txt completes pid 1 (turnaround 4), pid 3 (7), pid 0 (17) and then
+// pid 2 (24), so after each completion the average turnaround so far is 4, 5.5, 9.33 and finally the overall 13.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn stcf_running_averages() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("stcf")
+        .arg(workload)
+        .arg("--running-average")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let averages: Vec<&str> = stdout.lines().filter(|l| l.contains("running average")).collect();
+    assert_eq!(averages, vec![
+        "t=5 pid=1 completed, running average turnaround: 4",
+        "t=10 pid=3 completed, running average turnaround: 5.5",
+        "t=17 pid=0 completed, running average turnaround: 9.333333",
+        "t=26 pid=2 completed, running average turnaround: 13",
+    ]);
+    // the last running average is the overall one
+    assert!(stdout.lines().any(|l| l == "Average Turnaround Time: 13"));
+}
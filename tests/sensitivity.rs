@@ -0,0 +1,40 @@
+// Tests for --sensitivity on staggered.txt under fifo. pid 3 arrives last so fifo runs it last, making it longer can
+// only move its own completion, so the other processes' turnaround should stay the same and the average turnaround
+// should only change by delta / 4. pid 0 runs first, so every process completes delta later.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo with --sensitivity and returns the (before, after) of a line under "Sensitivity", and the estimate
+fn sensitivity(perturbation: &str, label: &str) -> ((f32, f32), f32) {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .args(["--sensitivity", perturbation])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // the metrics lines above it have the same labels
+    let line = stdout.lines().skip_while(|l| !l.starts_with("Sensitivity")).find_map(|l| l.trim().strip_prefix(label)).unwrap();
+    let (before, after) = line.split_once("->").unwrap();
+    let estimate = stdout.lines().find_map(|l| l.strip_prefix("Turnaround per Duration:")).unwrap();
+    ((before.trim().parse().unwrap(), after.trim().parse().unwrap()), estimate.trim().parse().unwrap())
+}
+
+#[test]
+fn last_process_only_changes_itself() {
+    let ((before, after), estimate) = sensitivity("3:5", "Others' Turnaround:");
+    assert_eq!(before, after);
+    assert!((estimate - 0.25).abs() < 1e-4);
+    let ((before, after), _) = sensitivity("3:5", "Average Turnaround Time:");
+    assert!((after - before - 1.25).abs() < 1e-4);
+}
+
+#[test]
+fn first_process_delays_everything() {
+    let ((before, after), estimate) = sensitivity("0:5", "Others' Turnaround:");
+    assert!((after - before - 5.0).abs() < 1e-4);
+    assert!((estimate - 1.0).abs() < 1e-4);
+}
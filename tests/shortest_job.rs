@@ -0,0 +1,43 @@
+// Tests for non-preemptive sjf and ljf. Without preemption the wrapper runs each picked process to completion in one
+// step, so a fractional duration finishes exactly at its length instead of being rounded up to a whole tick, and a
+// zero duration completes the moment it starts.
+
+use std::env;
+use std::process::Command;
+
+// writes the workload to a temp file ending in name (its extension picks the format), runs the algorithm on it and
+// returns the completions in pid order
+fn completions(algo: &str, name: &str, workload: &str) -> Vec<f32> {
+    let path = env::temp_dir().join(format!("final_scheduler_shortest_job_{}_{}", std::process::id(), name));
+    std::fs::write(&path, workload).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(&path)
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let field = |line: &str, name: &str| -> f32 {
+        line.split(", ").find_map(|f| f.strip_prefix(name)).unwrap().parse().unwrap()
+    };
+    let mut lines: Vec<(f32, f32)> = stdout
+        .lines()
+        .filter(|l| l.starts_with("\tpid="))
+        .map(|l| (field(l, "\tpid="), field(l, "completion=")))
+        .collect();
+    lines.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    lines.into_iter().map(|(_, completion)| completion).collect()
+}
+
+#[test]
+fn fractional_duration() {
+    let workload = r#"[{"arrival": 0, "duration": 2.5}, {"arrival": 0, "duration": 4}]"#;
+    assert_eq!(completions("sjf", "fractional.json", workload), vec![2.5, 6.5]);
+    assert_eq!(completions("fifo", "fractional.json", workload), vec![2.5, 6.5]);
+}
+
+#[test]
+fn zero_duration() {
+    assert_eq!(completions("sjf", "zero.txt", "0 0\n0 3\n"), vec![0.0, 3.0]);
+    assert_eq!(completions("ljf", "zero.txt", "0 0\n0 3\n"), vec![0.0, 3.0]);
+}
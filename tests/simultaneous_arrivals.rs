@@ -0,0 +1,25 @@
+// Tests for the warning printed before scheduling when every process arrives at the same time. oversubscribed.txt has
+// every process at 0, so response time there is only queue position, staggered.txt has a process arriving every tick.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+const WARNING: &str = "Warning: all processes arrive simultaneously; response times reflect queue position only";
+
+// runs fifo on a fixture and returns what it prints
+fn run(fixture: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("fifo").arg(workload).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn same_arrival_warns() {
+    assert!(run("oversubscribed.txt").lines().any(|l| l == WARNING));
+}
+
+#[test]
+fn staggered_does_not() {
+    assert!(!run("staggered.txt").contains(WARNING));
+}
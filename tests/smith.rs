@@ -0,0 +1,35 @@
+// Test for smith. Two jobs arrive at 0, pid 0 with duration 3 and weight 1 and pid 1 with duration 5 and weight 10.
+// sjf runs the shorter pid 0 first, Smith's rule runs pid 1 first because 10 / 5 is more than 1 / 3, which makes the
+// total weighted completion time 10 * 5 + 1 * 8 = 58 instead of sjf's 1 * 3 + 10 * 8 = 83.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+// runs an algorithm on the two jobs with --weighted-completion and returns what it prints
+fn run(algo: &str) -> String {
+    let path = env::temp_dir().join(format!("final_scheduler_smith_{algo}_{}.txt", std::process::id()));
+    fs::write(&path, "0 3 weight=1\n0 5 weight=10\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(&path)
+        .arg("--weighted-completion")
+        .output()
+        .unwrap();
+    fs::remove_file(&path).unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn higher_ratio_goes_first() {
+    let stdout = run("smith");
+    assert!(stdout.lines().any(|l| l.starts_with("\tpid=1, arrival=0, duration=5, first_run=0,")), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "Weighted Completion Time: 58"), "{stdout}");
+}
+
+#[test]
+fn sjf_goes_by_duration() {
+    let stdout = run("sjf");
+    assert!(stdout.lines().any(|l| l.starts_with("\tpid=0, arrival=0, duration=3, first_run=0,")), "{stdout}");
+    assert!(stdout.lines().any(|l| l == "Weighted Completion Time: 83"), "{stdout}");
+}
@@ -0,0 +1,36 @@
+// Tests for --spans on equal_pair.txt, two duration 4 processes arriving together. fifo runs each to completion in one
+// go, so every span is exactly the duration. rr alternates between them a tick at a time, so pid 0 first runs at 0 and
+// last stops at 7, a span of 7 for 4 ticks of work.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on equal_pair.txt with --spans and returns the span of every process, in the order printed
+fn spans(algo: &str) -> Vec<f32> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("equal_pair.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--spans")
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap().lines()
+        .skip_while(|l| *l != "Run Spans:").skip(1)
+        .take_while(|l| l.starts_with('\t'))
+        .map(|l| l.rsplit("span=").next().unwrap().parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn fifo_span_is_the_duration() {
+    assert_eq!(spans("fifo"), vec![4.0, 4.0]);
+}
+
+#[test]
+fn rr_span_is_longer_than_the_duration() {
+    let spans = spans("rr");
+    assert_eq!(spans.len(), 2);
+    assert!(spans.iter().all(|&span| span > 4.0));
+    assert_eq!(spans[0], 7.0);
+}
@@ -0,0 +1,77 @@
+// Tests for --speed. A process does speed units of work per unit of time, so on a single duration 10 job with no
+// other processes halving the speed has to exactly double when it completes, and doubling it halves it. That goes for
+// every algorithm that takes the speed, not only the ones --tick works with.
+
+use std::env;
+use std::process::Command;
+
+// runs the algorithm on a workload of one duration 10 job at the speed and returns its completion time
+fn completion(algo: &str, speed: &str) -> String {
+    let path = env::temp_dir().join(format!("final_scheduler_speed_{}_{algo}_{speed}.txt", std::process::id()));
+    std::fs::write(&path, "0 10\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(&path)
+        .args(["--speed", speed])
+        .output()
+        .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line = stdout.lines().find(|l| l.trim().starts_with("pid=0,")).unwrap().to_string();
+    line.rsplit("completion=").next().unwrap().split(',').next().unwrap().to_string()
+}
+
+#[test]
+fn half_speed_doubles_the_completion() {
+    assert_eq!(completion("rr", "1"), "10");
+    assert_eq!(completion("rr", "0.5"), "20");
+}
+
+#[test]
+fn double_speed_halves_the_completion() {
+    for algo in ["rr", "stcf", "mlfq"] {
+        assert_eq!(completion(algo, "2"), "5", "{algo}");
+    }
+}
+
+#[test]
+fn two_level_follows_the_speed() {
+    assert_eq!(completion("two-level", "0.5"), "20");
+    assert_eq!(completion("two-level", "2"), "5");
+}
+
+#[test]
+fn cfs_follows_the_speed() {
+    assert_eq!(completion("cfs", "0.5"), "20");
+    assert_eq!(completion("cfs", "2"), "5");
+}
+
+#[test]
+fn edf_follows_the_speed() {
+    assert_eq!(completion("edf", "0.5"), "20");
+    assert_eq!(completion("edf", "2"), "5");
+}
+
+#[test]
+fn hybrid_follows_the_speed() {
+    assert_eq!(completion("hybrid", "0.5"), "20");
+    assert_eq!(completion("hybrid", "2"), "5");
+}
+
+#[test]
+fn aging_follows_the_speed() {
+    assert_eq!(completion("aging", "0.5"), "20");
+    assert_eq!(completion("aging", "2"), "5");
+}
+
+#[test]
+fn rr_classed_follows_the_speed() {
+    assert_eq!(completion("rr-classed", "0.5"), "20");
+    assert_eq!(completion("rr-classed", "2"), "5");
+}
+
+#[test]
+fn rr_multi_follows_the_speed() {
+    assert_eq!(completion("rr-multi", "0.5"), "20");
+    assert_eq!(completion("rr-multi", "2"), "5");
+}
@@ -0,0 +1,73 @@
+// Test for the --sqlite output, only built with "cargo test --features sqlite". It runs the binary twice on a small
+// workload into a fresh database and reads the rows back through the same SQLite C library the program uses.
+#![cfg(feature = "sqlite")]
+
+use std::env;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[link(name = "sqlite3")]
+extern "C" {
+    fn sqlite3_open(filename: *const c_char, db: *mut *mut c_void) -> c_int;
+    fn sqlite3_exec(
+        db: *mut c_void,
+        sql: *const c_char,
+        callback: extern "C" fn(*mut c_void, c_int, *mut *mut c_char, *mut *mut c_char) -> c_int,
+        arg: *mut c_void,
+        errmsg: *mut *mut c_char,
+    ) -> c_int;
+    fn sqlite3_close(db: *mut c_void) -> c_int;
+}
+
+// sqlite3_exec calls this once per row, arg is the Vec the rows go in
+extern "C" fn collect_row(arg: *mut c_void, columns: c_int, values: *mut *mut c_char, _names: *mut *mut c_char) -> c_int {
+    let rows = unsafe { &mut *(arg as *mut Vec<Vec<String>>) };
+    let row = (0..columns as usize)
+        .map(|i| unsafe { CStr::from_ptr(*values.add(i)).to_string_lossy().into_owned() })
+        .collect();
+    rows.push(row);
+    0
+}
+
+// runs a query and returns every row as strings
+fn query(db_path: &Path, sql: &str) -> Vec<Vec<String>> {
+    let path = CString::new(db_path.to_str().unwrap()).unwrap();
+    let sql = CString::new(sql).unwrap();
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    unsafe {
+        let mut db = std::ptr::null_mut();
+        assert_eq!(sqlite3_open(path.as_ptr(), &mut db), 0);
+        let code = sqlite3_exec(db, sql.as_ptr(), collect_row, &mut rows as *mut _ as *mut c_void, std::ptr::null_mut());
+        sqlite3_close(db);
+        assert_eq!(code, 0, "query failed: {}", sql.to_string_lossy());
+    }
+    rows
+}
+
+#[test]
+fn sqlite_rows_are_appended() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let db_path = env::temp_dir().join(format!("final_scheduler_test_{}.db", std::process::id()));
+    let _ = fs::remove_file(&db_path);
+
+    for algo in ["fifo", "rr"] {
+        let status = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+            .args([algo, workload.to_str().unwrap(), "--sqlite", db_path.to_str().unwrap()])
+            .output()
+            .unwrap();
+        assert!(status.status.success());
+    }
+
+    let processes = fs::read_to_string(&workload).unwrap().lines().count();
+    let rows = query(&db_path, "SELECT algorithm, COUNT(*) FROM results GROUP BY algorithm ORDER BY algorithm");
+    assert_eq!(rows, vec![vec!["fifo".to_string(), processes.to_string()], vec!["rr".to_string(), processes.to_string()]]);
+
+    // fifo runs the first arrival (pid 0 in this fixture) right away
+    let rows = query(&db_path, "SELECT first_run, workload_name FROM results WHERE algorithm = 'fifo' AND pid = 0");
+    assert_eq!(rows[0][0].parse::<f32>().unwrap(), 0.0);
+    assert_eq!(rows[0][1], workload.to_str().unwrap());
+
+    fs::remove_file(&db_path).unwrap();
+}
@@ -0,0 +1,30 @@
+// Tests for the start_level column in mlfq. In start_level.txt pid 0 starts in the lowest level (3) while pids 1 and 2
+// start at the top, all arriving at 0, so pid 0 can't run until the other two have worked their way down and finished
+// at 4. start_level_past_bottom.txt asks for level 99, which should be the lowest level, not a crash.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs mlfq on the fixture with --dispatch-order and returns the dispatch order
+fn dispatch_order(fixture: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("mlfq")
+        .arg(workload)
+        .args(["--quiet", "--dispatch-order"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find_map(|l| l.strip_prefix("Dispatch Order: ")).unwrap().to_string()
+}
+
+#[test]
+fn lowest_level_runs_last() {
+    assert_eq!(dispatch_order("start_level.txt"), "2 1 2 1 0");
+}
+
+#[test]
+fn past_the_bottom_is_the_bottom() {
+    assert_eq!(dispatch_order("start_level_past_bottom.txt"), "1 0");
+}
@@ -0,0 +1,34 @@
+// Test for --detect-starvation. In starvation.txt a duration 20 job arrives with a short one at time 0, and after that
+// a duration 2 job arrives every 2 ticks, each just as the one before it finishes. sjf always picks the short job, so
+// the long one waits until all of them are done at time 21.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs sjf on starvation.txt with the threshold and returns what it prints
+fn detect(threshold: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("starvation.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("sjf")
+        .arg(workload)
+        .args(["--detect-starvation", threshold])
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn long_job_is_flagged() {
+    let stdout = detect("15");
+    let flagged: Vec<&str> = stdout.lines().skip_while(|l| !l.starts_with("Starved Processes")).skip(1)
+        .take_while(|l| l.starts_with('\t'))
+        .map(|l| l.trim())
+        .collect();
+    assert_eq!(flagged, vec!["pid=1, waited=21"]);
+}
+
+#[test]
+fn nothing_under_the_threshold() {
+    assert!(detect("21").lines().any(|l| l == "No process waited longer than 21"));
+}
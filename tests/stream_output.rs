@@ -0,0 +1,27 @@
+// Test for --stream-output. stcf on staggered.txt completes pid 1 at 5, pid 3 at 10, pid 0 at 17 and pid 2 at 26, and
+// each should be printed as a "Completed:" line when it completes, so all of them come before the usual output at the
+// end, in that order.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn completion_order_before_the_summary() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("stcf")
+        .arg(workload)
+        .arg("--stream-output")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    let summary = lines.iter().position(|l| *l == "Processes:").unwrap();
+    let streamed: Vec<&str> = lines.iter().filter_map(|l| l.strip_prefix("Completed: ")).collect();
+    assert_eq!(lines[..summary].len(), streamed.len());
+    let pids: Vec<&str> = streamed.iter().map(|l| l.split(", ").next().unwrap()).collect();
+    assert_eq!(pids, vec!["pid=1", "pid=3", "pid=0", "pid=2"]);
+    let completions: Vec<f32> = streamed.iter().map(|l| l.rsplit("completion=").next().unwrap().parse().unwrap()).collect();
+    assert_eq!(completions, vec![5.0, 10.0, 17.0, 26.0]);
+}
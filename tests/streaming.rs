@@ -0,0 +1,40 @@
+// Tests for --stream. Streaming fifo schedules the file while reading it, and on a file that is sorted by arrival it
+// should print exactly what batch fifo prints, every process line and every metric.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on a fixture with extra options and returns everything it prints
+fn fifo(fixture: &str, extra_args: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(workload)
+        .args(extra_args)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn streaming_matches_batch() {
+    for fixture in ["mixed_40.txt", "staggered.txt", "simultaneous.txt", "long_first.txt"] {
+        // streaming hasn't read the later lines when it starts, so only batch can warn that every arrival is the same
+        let batch = fifo(fixture, &[]).replace("Warning: all processes arrive simultaneously; response times reflect queue position only\n", "");
+        assert_eq!(fifo(fixture, &["--stream"]), batch, "{fixture}");
+    }
+    assert_eq!(
+        fifo("delta_arrivals.txt", &["--stream", "--arrivals", "delta"]),
+        fifo("delta_arrivals.txt", &["--arrivals", "delta"])
+    );
+}
+
+#[test]
+fn unsorted_file_is_an_error() {
+    let stdout = fifo("errors/unsorted.txt", &["--stream"]);
+    assert_eq!(
+        stdout.lines().last().unwrap(),
+        "Error: line 3: arrives before the line above it, streaming needs the file sorted by arrival"
+    );
+}
@@ -0,0 +1,32 @@
+// Tests for the quantum rr suggests when it runs without --quantum. The suggestion is half the median duration, so for
+// mixed_40.txt it has to be at least 1 and at most the median, and it isn't printed when a quantum is given.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr on mixed_40.txt with the arguments and returns what it prints
+fn run(args: &[&str]) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("mixed_40.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("rr").arg(workload).args(args).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn suggestion_is_within_bounds() {
+    let stdout = run(&[]);
+    let note = stdout.lines().find(|l| l.starts_with("Note: no --quantum given")).unwrap();
+    let suggested: f32 = note.split("duration, ").nth(1).unwrap().split(',').next().unwrap().parse().unwrap();
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("mixed_40.txt");
+    let mut durations: Vec<f32> = std::fs::read_to_string(workload).unwrap().lines()
+        .filter_map(|l| l.split_whitespace().nth(1).and_then(|d| d.parse().ok())).collect();
+    durations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let median = durations[durations.len() / 2];
+    assert!(suggested >= 1.0, "{suggested}");
+    assert!(suggested <= median, "{suggested} > {median}");
+}
+
+#[test]
+fn no_suggestion_with_a_quantum() {
+    assert!(!run(&["--quantum", "3"]).contains("Note: no --quantum given"));
+}
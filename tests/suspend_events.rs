@@ -0,0 +1,40 @@
+// Tests for rr with --events. suspend.txt is a single process of duration 4, so nothing else can use the CPU while it is
+// suspended: suspending it for 5 ticks (events/suspend_5.txt, suspended at 1 and resumed at 6) has to move its completion
+// exactly 5 later, and none of that time is waiting in the ready queue.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr on suspend.txt, with the events file from tests/fixtures/events if one is given, and returns what it prints
+fn run_rr(events: Option<&str>) -> String {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures");
+    let mut command = Command::new(env!("CARGO_BIN_EXE_final_scheduler"));
+    command.arg("rr").arg(fixtures.join("suspend.txt"));
+    if let Some(events) = events {
+        command.arg("--events").arg(fixtures.join("events").join(events));
+    }
+    String::from_utf8(command.output().unwrap().stdout).unwrap()
+}
+
+// the value of a field from the line of pid 0
+fn field(stdout: &str, name: &str) -> f32 {
+    let line = stdout.lines().find(|l| l.trim().starts_with("pid=0,")).unwrap();
+    let value = line.split(", ").find_map(|f| f.trim().strip_prefix(&format!("{name}="))).unwrap();
+    value.parse().unwrap()
+}
+
+#[test]
+fn suspending_delays_completion() {
+    let plain = run_rr(None);
+    let suspended = run_rr(Some("suspend_5.txt"));
+    assert_eq!(field(&suspended, "completion"), field(&plain, "completion") + 5.0);
+    assert_eq!(field(&suspended, "ready_wait"), 0.0);
+}
+
+#[test]
+fn never_resumed_is_incomplete() {
+    let stdout = run_rr(Some("never_resumed.txt"));
+    assert!(stdout.contains("1 processes incomplete"));
+    assert!(stdout.contains("pid=0, arrival=0, duration=4, remaining_time=1"));
+}
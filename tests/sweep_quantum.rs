@@ -0,0 +1,22 @@
+// Test for --sweep-quantum: 1:4:1 on staggered.txt should give a header and one row per quantum, 1 to 4, and a longer
+// quantum can only mean fewer context switches on it.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn one_row_per_quantum() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(workload)
+        .args(["--sweep-quantum", "1:4:1"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<Vec<&str>> = stdout.lines().skip(1).map(|l| l.split_whitespace().collect()).collect();
+    let quanta: Vec<&str> = rows.iter().map(|row| row[0]).collect();
+    assert_eq!(quanta, vec!["1", "2", "3", "4"]);
+    let switches: Vec<usize> = rows.iter().map(|row| row[2].parse().unwrap()).collect();
+    assert!(switches.windows(2).all(|w| w[1] <= w[0]), "{switches:?}");
+}
@@ -0,0 +1,39 @@
+// Tests for --tick. half_tick.json has a duration 2.5 process and a duration 1 process arriving together. rr with
+// steps of a whole tick can only stop pid 0 on whole numbers, its last tick overshoots and it completes at 4. With
+// --tick 0.5 the quantum of 1 is two steps, so the schedule is the same up to pid 0's last half tick, and it completes
+// exactly at 3.5 (1 tick, then pid 1's tick, then the remaining 1.5).
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr on half_tick.json with the tick and returns the completion of every pid, in pid order
+fn completions(tick: &str) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("half_tick.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .args(["--tick", tick])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let mut processes: Vec<(String, String)> = stdout.lines()
+        .filter_map(|l| l.trim().strip_prefix("pid="))
+        .map(|l| {
+            let pid = l.split(',').next().unwrap().to_string();
+            (pid, l.rsplit("completion=").next().unwrap().split(',').next().unwrap().to_string())
+        })
+        .collect();
+    processes.sort();
+    processes.into_iter().map(|(_, completion)| completion).collect()
+}
+
+#[test]
+fn half_ticks_complete_on_the_half() {
+    assert_eq!(completions("0.5"), vec!["3.5", "2"]);
+}
+
+#[test]
+fn whole_ticks_overshoot() {
+    assert_eq!(completions("1"), vec!["4", "2"]);
+}
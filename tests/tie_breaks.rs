@@ -0,0 +1,51 @@
+// Tests for how the heap based algorithms break ties. In ties.txt pids 1 and 3 arrive together at 0, and pids 0 and 2
+// arrive together at 2, when pid 3 has 1 tick left, as much as pid 2 needs. Equal arrivals always go in pid order and
+// equal remaining times go to the process that arrived first, so each algorithm's dispatch order is always exactly
+// the one below.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs an algorithm on ties.txt and returns its dispatch order line
+fn dispatch_order(algo: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("ties.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--dispatch-order", "--quiet"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("Dispatch Order:")).unwrap().to_string()
+}
+
+// sjf starts pid 1 (the lower pid of the two at 0) right away, then runs the rest shortest first
+#[test]
+fn sjf() {
+    assert_eq!(dispatch_order("sjf"), "Dispatch Order: 1 2 3 0");
+}
+
+// pid 3 keeps the CPU at 2 against pid 2, which is as short but arrived later
+#[test]
+fn stcf() {
+    assert_eq!(dispatch_order("stcf"), "Dispatch Order: 3 2 0 1");
+}
+
+// pid 1 is put on first and pid 3 joins the queue behind it, then 0 joins before 2
+#[test]
+fn rr() {
+    assert_eq!(dispatch_order("rr"), "Dispatch Order: 1 3 1 3 0 2 1 3 0 1 0 1 0 1");
+}
+
+// arrivals go to the front of the first level, so pid 3 goes in front of pid 1 and pid 2 in front of pid 0
+#[test]
+fn mlfq() {
+    assert_eq!(dispatch_order("mlfq"), "Dispatch Order: 3 1 2 0 3 1 0 3 1 0 1 0 1");
+}
+
+// every process has priority 0, so priority is first come first served with pid order for the ties
+#[test]
+fn priority() {
+    assert_eq!(dispatch_order("priority"), "Dispatch Order: 1 3 0 2");
+}
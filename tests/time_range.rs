@@ -0,0 +1,35 @@
+// Tests for times near the end of the range f32 holds exactly (2^24 = 16777216). late_start.txt is the workload
+// "0 5, 0 3, 2 4" moved to start at 16000000, so rr has to give the same schedule as on the original, every time
+// shifted by exactly 16000000 (on the original rr completes pid 1 at 7, pid 0 at 11 and pid 2 at 12).
+// errors/past_exact_time.txt could run past 2^24, so it is refused instead of scheduled.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr on the fixture and returns what it prints
+fn rr(fixture: &[&str]) -> String {
+    let mut path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures");
+    for part in fixture {
+        path = path.join(part);
+    }
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("rr").arg(path).output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn late_completions_are_exact() {
+    let stdout = rr(&["late_start.txt"]);
+    let completions: Vec<&str> = stdout.lines()
+        .filter(|l| l.trim().starts_with("pid="))
+        .map(|l| l.split(", ").find_map(|f| f.strip_prefix("completion=")).unwrap())
+        .collect();
+    assert_eq!(completions, vec!["16000007", "16000011", "16000012"]);
+}
+
+#[test]
+fn past_the_exact_range_is_refused() {
+    let stdout = rr(&["errors", "past_exact_time.txt"]);
+    assert_eq!(stdout.lines().next().unwrap(),
+        "Error: the schedule could run until t=16778005, times past 16777216 can't be simulated exactly");
+}
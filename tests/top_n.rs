@@ -0,0 +1,36 @@
+// Tests for --top-n. With --top-n 3 only the 3 processes with the longest turnaround are printed, longest first, and
+// they have to be exactly the top 3 of the full listing of the same run. In arrival_ranges.txt pid 0 and pid 3 both
+// have a turnaround of 6 under stcf, behind pid 2 with 10, so the tie goes to the lower pid.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs stcf on a fixture with the arguments and returns the process lines it prints
+fn processes(fixture: &str, args: &[&str]) -> Vec<String> {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler")).arg("stcf").arg(workload).args(args).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().filter(|l| l.starts_with("\tpid=")).map(|l| l.to_string()).collect()
+}
+
+// a field of a process line, like completion in "\tpid=0, arrival=0, ..., completion=8, ..."
+fn field(line: &str, name: &str) -> f32 {
+    line.split(", ").find_map(|c| c.trim().strip_prefix(&format!("{name}="))).unwrap().parse().unwrap()
+}
+
+#[test]
+fn top_three_of_the_full_listing() {
+    let mut all = processes("mixed_40.txt", &[]);
+    all.sort_by(|a, b| {
+        let turnaround = |l: &str| field(l, "completion") - field(l, "arrival");
+        turnaround(b).partial_cmp(&turnaround(a)).unwrap().then((field(a, "pid") as usize).cmp(&(field(b, "pid") as usize)))
+    });
+    assert_eq!(processes("mixed_40.txt", &["--top-n", "3"]), all[..3].to_vec());
+}
+
+#[test]
+fn ties_in_pid_order() {
+    let pids: Vec<f32> = processes("arrival_ranges.txt", &["--top-n", "3"]).iter().map(|l| field(l, "pid")).collect();
+    assert_eq!(pids, vec![2.0, 0.0, 3.0]);
+}
@@ -0,0 +1,34 @@
+// Test for --vcd. rr on staggered.txt runs 4 processes, so the VCD should declare 4 signals, and every dispatch in the
+// dispatch order is one segment, which is two value changes after the initial $dumpvars: the process going high when
+// it starts and low when it stops.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn one_signal_per_pid_and_two_changes_per_segment() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("staggered.txt");
+    let vcd = env::temp_dir().join(format!("final_scheduler_vcd_{}.vcd", std::process::id()));
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .args(["--dispatch-order", "--vcd"])
+        .arg(&vcd)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let dispatches = stdout.lines().find_map(|l| l.strip_prefix("Dispatch Order:")).unwrap().split_whitespace().count();
+
+    let text = fs::read_to_string(&vcd).unwrap();
+    fs::remove_file(&vcd).unwrap();
+    assert!(text.contains("$enddefinitions $end"));
+    let signals: Vec<&str> = text.lines().filter(|l| l.starts_with("$var wire 1 ")).collect();
+    assert_eq!(signals.len(), 4);
+    // everything after the $end of $dumpvars, the value changes and the #time lines between them
+    let changes = text.lines().skip_while(|l| *l != "$dumpvars").skip_while(|l| *l != "$end").skip(1)
+        .filter(|l| l.starts_with('0') || l.starts_with('1'))
+        .count();
+    assert_eq!(changes, 2 * dispatches);
+}
@@ -0,0 +1,31 @@
+// Test for --vs-sjf. It runs sjf on the same workload and prints how much worse the chosen algorithm's average
+// turnaround is than sjf's, so when the chosen algorithm is sjf itself the difference has to be exactly 0.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on the fixture with --vs-sjf and returns the line it prints
+fn vs_sjf(algo: &str, fixture: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .arg("--vs-sjf")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("Turnaround vs SJF:")).unwrap().to_string()
+}
+
+#[test]
+fn sjf_against_itself_is_zero() {
+    for fixture in ["staggered.txt", "mixed_40.txt", "long_first.txt"] {
+        assert!(vs_sjf("sjf", fixture).starts_with("Turnaround vs SJF:       +0.0% "), "{fixture}");
+    }
+}
+
+#[test]
+fn fifo_is_worse_than_sjf() {
+    assert_eq!(vs_sjf("fifo", "staggered.txt"), "Turnaround vs SJF:       +7.0% (SJF average turnaround 14.25)");
+}
@@ -0,0 +1,58 @@
+// Tests for --window-metrics 10 on fifo. arrival_ranges.txt completes at 4, 6, 12, 15, 16 and 27 with turnarounds 4,
+// 5, 10, 5, 4 and 2, and the windows slide by 5, so every process is in two of them. Averaging the windows by how many
+// processes each has gives back the overall average, and that is checked on mixed_40.txt under rr too.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs the algorithm on a fixture with --window-metrics and returns what it prints
+fn run(algo: &str, fixture: &str, width: &str) -> String {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg(algo)
+        .arg(workload)
+        .args(["--window-metrics", width])
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+// the (count, average turnaround) of every window line
+fn windows(stdout: &str) -> Vec<(f32, f32)> {
+    stdout
+        .lines()
+        .skip_while(|l| *l != "Turnaround by Completion Window:")
+        .skip(1)
+        .map(|l| {
+            let (count, turnaround) = l.split_once(": ").unwrap().1.split_once(" processes, average turnaround ").unwrap();
+            (count.parse().unwrap(), turnaround.parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn averages_per_window() {
+    let stdout = run("fifo", "arrival_ranges.txt", "10");
+    let lines: Vec<&str> = stdout.lines().skip_while(|l| *l != "Turnaround by Completion Window:").skip(1).map(|l| l.trim()).collect();
+    assert_eq!(lines, vec![
+        "[0, 5): 1 processes, average turnaround 4",
+        "[0, 10): 2 processes, average turnaround 4.5",
+        "[5, 15): 2 processes, average turnaround 7.5",
+        "[10, 20): 3 processes, average turnaround 6.3333335",
+        "[15, 25): 2 processes, average turnaround 4.5",
+        "[20, 30): 1 processes, average turnaround 2",
+        "[25, 35): 1 processes, average turnaround 2",
+    ]);
+}
+
+#[test]
+fn windows_average_to_the_global_average() {
+    let stdout = run("rr", "mixed_40.txt", "25");
+    let global: f32 = stdout.lines().find_map(|l| l.strip_prefix("Average Turnaround Time:")).unwrap().trim().parse().unwrap();
+    let windows = windows(&stdout);
+    let count: f32 = windows.iter().map(|w| w.0).sum();
+    let total: f32 = windows.iter().map(|w| w.0 * w.1).sum();
+    assert_eq!(count, 80.0);
+    assert!((total / count - global).abs() < 1e-3, "{} vs {global}", total / count);
+}
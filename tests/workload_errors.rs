@@ -0,0 +1,85 @@
+// Tests for the errors read_workload reports on malformed workloads. Every file in tests/fixtures/errors is broken in
+// one way, and each test checks the binary prints the message of the one WorkloadError variant that way of being broken
+// should map to (the messages come from WorkloadError's Display, one per variant). The exact line is compared, so a
+// file that starts failing as a different variant, or on a different line number, fails here.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+// runs fifo on a file in tests/fixtures/errors and returns the first line it prints
+fn first_line(fixture: &str) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("errors").join(fixture);
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(path)
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap().lines().next().unwrap_or("").to_string()
+}
+
+#[test]
+fn io() {
+    assert!(first_line("does_not_exist.txt").starts_with("Error: could not read the workload file: "));
+}
+
+#[test]
+fn empty() {
+    assert_eq!(first_line("empty.txt"), "Error: the workload file has no processes");
+}
+
+#[test]
+fn missing_column() {
+    assert_eq!(first_line("single_column.txt"), "Error: line 2: needs an arrival and a duration");
+}
+
+#[test]
+fn bad_number() {
+    assert_eq!(first_line("non_numeric.txt"), "Error: line 2: abc is not a whole number");
+}
+
+#[test]
+fn negative_duration() {
+    assert_eq!(first_line("negative_duration.txt"), "Error: line 3: duration -2 is negative");
+}
+
+#[test]
+fn bad_column() {
+    assert_eq!(first_line("bad_column.txt"), "Error: line 1: colour=red: unknown workload column colour");
+}
+
+// --lenient reads the whole file, so every bad line in scattered.txt is reported, in order and with its own line
+// number, and the good lines still get scheduled
+#[test]
+fn lenient_reports_every_line() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("errors").join("scattered.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("fifo")
+        .arg(path)
+        .arg("--lenient")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let warnings: Vec<&str> = stdout.lines().filter(|l| l.starts_with("Warning: ")).collect();
+    assert_eq!(warnings, vec![
+        "Warning: line 2: abc is not a whole number",
+        "Warning: line 4: needs an arrival and a duration",
+        "Warning: line 6: duration -1 is negative",
+        "Warning: line 7: colour=red: unknown workload column colour",
+    ]);
+    // the 4 good lines keep the pids of their lines
+    let pids: Vec<&str> = stdout.lines().filter_map(|l| l.trim().strip_prefix("pid=")).map(|l| l.split(',').next().unwrap()).collect();
+    assert_eq!(pids, vec!["0", "2", "4", "7"]);
+}
+
+#[test]
+fn strict_stops_at_the_first_line() {
+    assert_eq!(first_line("scattered.txt"), "Error: line 2: abc is not a whole number");
+}
+
+// a .json workload goes through its own parser, which reports the character it stopped at instead of a line
+#[test]
+fn bad_json() {
+    assert_eq!(first_line("truncated.json"), "Error: character 17 of the JSON workload: expected '\"' but the file ended");
+    assert_eq!(first_line("missing_field.json"), "Error: character 34 of the JSON workload: the process has no duration");
+}
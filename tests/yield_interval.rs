@@ -0,0 +1,31 @@
+// Test for the yield_interval column under rr. In yield.txt pid 0 yields after every tick it runs and pid 1 doesn't,
+// both need 6 and the quantum is 3. pid 0 gives up the CPU after 1 tick each time instead of 3, so it is dispatched at
+// 0, 4 and 8 (the last time it is alone and runs to the end), where without the column the two just take turns.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+// runs rr with a quantum of 3 on the workload and returns the dispatch order line
+fn dispatch_order(workload: &Path) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_final_scheduler"))
+        .arg("rr")
+        .arg(workload)
+        .args(["--quantum", "3", "--dispatch-order"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.lines().find(|l| l.starts_with("Dispatch Order:")).unwrap().to_string()
+}
+
+#[test]
+fn yielding_process_gives_up_the_cpu_more_often() {
+    let workload = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("yield.txt");
+    assert_eq!(dispatch_order(&workload), "Dispatch Order: 0 1 0 1 0");
+
+    let plain = env::temp_dir().join(format!("final_scheduler_yield_{}.txt", std::process::id()));
+    fs::write(&plain, "0 6\n0 6\n").unwrap();
+    assert_eq!(dispatch_order(&plain), "Dispatch Order: 0 1 0 1");
+    fs::remove_file(plain).unwrap();
+}